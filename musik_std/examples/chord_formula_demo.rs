@@ -209,7 +209,7 @@ fn main() {
     );
     println!(
         "  Degrees in Dom13♯11: {}",
-        complex_jazz_chord.degrees().len()
+        complex_jazz_chord.degree_count()
     );
 
     println!("\n✅ ChordFormula provides efficient, comprehensive chord representation!");
@@ -0,0 +1,417 @@
+//! Harmonic analysis of pitch-class collections.
+//!
+//! This module provides the `analyze` entry point, which recognizes whether a
+//! set of pitch classes forms a known chord or scale, `detect_key`, which
+//! estimates the key of a pitch-class distribution, and `chroma_vector`, which
+//! builds that distribution from weighted notes.
+
+use crate::chord_formula::{ChordFormula, CATALOG};
+use crate::pitch_class::PitchClass;
+use crate::scale_formula::ScaleFormula;
+use crate::semitone::SEMITONES_IN_OCTAVE;
+
+/// Largest pitch-class set considered as a chord.
+///
+/// A full thirteenth chord spells every note of a diatonic scale, so sets of
+/// seven or more pitch classes are only compared against scales.
+const MAX_CHORD_NOTES: usize = 6;
+
+/// Scale formulas recognized by [`analyze`].
+const SCALES: [ScaleFormula; 6] = [
+    ScaleFormula::major(),
+    ScaleFormula::minor(),
+    ScaleFormula::pentatonic_major(),
+    ScaleFormula::pentatonic_minor(),
+    ScaleFormula::blues(),
+    ScaleFormula::chromatic(),
+];
+
+/// Krumhansl-Kessler major key profile, indexed by semitones above the tonic.
+const MAJOR_PROFILE: [f64; SEMITONES_IN_OCTAVE as usize] = [
+    6.35, 2.23, 3.48, 2.33, 4.38, 4.09, 2.52, 5.19, 2.39, 3.66, 2.29, 2.88,
+];
+
+/// Krumhansl-Kessler minor key profile, indexed by semitones above the tonic.
+const MINOR_PROFILE: [f64; SEMITONES_IN_OCTAVE as usize] = [
+    6.33, 2.68, 3.52, 5.38, 2.60, 3.53, 2.54, 4.75, 3.98, 2.69, 3.34, 3.17,
+];
+
+/// The result of analyzing a collection of pitch classes.
+///
+/// # Examples
+/// ```
+/// use musik_std::{analyze, AnalysisResult, ChordFormula, C, E, G};
+///
+/// match analyze(&[C, E, G]) {
+///     AnalysisResult::Chord(candidates) => {
+///         assert_eq!(candidates[0], (C, ChordFormula::major_triad()));
+///     }
+///     _ => panic!("C, E, G should be recognized as a chord"),
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnalysisResult {
+    /// The pitch classes form a known chord, listed with every possible root.
+    Chord(Vec<(PitchClass, ChordFormula)>),
+    /// The pitch classes form a known scale, listed with every possible root.
+    Scale(Vec<(PitchClass, ScaleFormula)>),
+    /// The pitch classes match neither a known chord nor a known scale.
+    Unknown,
+}
+
+/// Recognizes whether a collection of pitch classes forms a known chord or scale.
+///
+/// Each distinct pitch class is tried as a root, in input order, and the
+/// resulting interval set is compared against the built-in chord and scale
+/// formulas. Chords take precedence over scales, except for sets of seven or
+/// more pitch classes, which are only matched as scales. Every matching
+/// `(root, formula)` pair is returned, so ambiguous sets (such as C6 and Am7)
+/// report all their interpretations.
+///
+/// # Examples
+/// ```
+/// use musik_std::{analyze, AnalysisResult, ScaleFormula, A, B, C, C_SHARP, D, E, F, G};
+///
+/// // The white keys form C major (and its relative, A minor)
+/// match analyze(&[C, D, E, F, G, A, B]) {
+///     AnalysisResult::Scale(candidates) => {
+///         assert_eq!(candidates[0], (C, ScaleFormula::major()));
+///         assert!(candidates.contains(&(A, ScaleFormula::minor())));
+///     }
+///     _ => panic!("the white keys should be recognized as a scale"),
+/// }
+///
+/// // A chromatic cluster is neither
+/// assert_eq!(analyze(&[C, C_SHARP, D]), AnalysisResult::Unknown);
+/// ```
+pub fn analyze(notes: &[PitchClass]) -> AnalysisResult {
+    let mut roots: Vec<PitchClass> = Vec::new();
+    for &note in notes {
+        if !roots.contains(&note) {
+            roots.push(note);
+        }
+    }
+
+    if roots.len() <= MAX_CHORD_NOTES {
+        let chords: Vec<(PitchClass, ChordFormula)> = roots
+            .iter()
+            .flat_map(|&root| {
+                let mask = interval_mask(root, &roots);
+                CATALOG
+                    .iter()
+                    .filter(move |(_, chord)| chord_mask(chord) == mask)
+                    .map(move |&(_, chord)| (root, chord))
+            })
+            .collect();
+
+        if !chords.is_empty() {
+            return AnalysisResult::Chord(chords);
+        }
+    }
+
+    let scales: Vec<(PitchClass, ScaleFormula)> = roots
+        .iter()
+        .flat_map(|&root| {
+            let mask = interval_mask(root, &roots);
+            SCALES
+                .iter()
+                .filter(move |scale| scale.bits() == mask)
+                .map(move |&scale| (root, scale))
+        })
+        .collect();
+
+    if !scales.is_empty() {
+        return AnalysisResult::Scale(scales);
+    }
+
+    AnalysisResult::Unknown
+}
+
+/// Builds a 12-bit mask of the intervals from `root` to each pitch class.
+fn interval_mask(root: PitchClass, pitch_classes: &[PitchClass]) -> u32 {
+    pitch_classes.iter().fold(0, |mask, pitch_class| {
+        let interval =
+            (pitch_class.value() + SEMITONES_IN_OCTAVE - root.value()) % SEMITONES_IN_OCTAVE;
+        mask | (1 << interval)
+    })
+}
+
+/// Builds a 12-bit mask of the semitone offsets in a chord formula.
+fn chord_mask(chord: &ChordFormula) -> u32 {
+    chord
+        .semitone_offsets()
+        .into_iter()
+        .fold(0, |mask, offset| mask | (1 << offset))
+}
+
+/// Estimates the key of a pitch-class distribution.
+///
+/// Implements the Krumhansl-Schmuckler algorithm: the distribution is correlated
+/// against the major and minor key profiles rotated to each of the 12 tonics.
+/// All 24 candidate keys are returned as `(tonic, formula, correlation)`, ranked
+/// from the best match down. Repeated pitch classes in the input are summed.
+///
+/// Returns an empty list when the distribution carries no information, that is
+/// when every pitch class has the same count.
+///
+/// # Examples
+/// ```
+/// use musik_std::{detect_key, ScaleFormula, A, B, C, D, E, F, G};
+///
+/// let counts = [(C, 10), (D, 4), (E, 7), (F, 4), (G, 8), (A, 4), (B, 3)];
+/// let candidates = detect_key(&counts);
+///
+/// let (tonic, formula, _) = candidates[0];
+/// assert_eq!((tonic, formula), (C, ScaleFormula::major()));
+/// ```
+pub fn detect_key(note_counts: &[(PitchClass, u32)]) -> Vec<(PitchClass, ScaleFormula, f64)> {
+    let weighted: Vec<(PitchClass, f64)> = note_counts
+        .iter()
+        .map(|&(pitch_class, count)| (pitch_class, count as f64))
+        .collect();
+    let distribution = chroma_vector(&weighted);
+
+    let profiles = [
+        (ScaleFormula::major(), &MAJOR_PROFILE),
+        (ScaleFormula::minor(), &MINOR_PROFILE),
+    ];
+
+    let mut candidates = Vec::new();
+    for (formula, profile) in profiles {
+        for tonic in PitchClass::all() {
+            let rotated: [f64; SEMITONES_IN_OCTAVE as usize] = std::array::from_fn(|index| {
+                let interval =
+                    (index as u8 + SEMITONES_IN_OCTAVE - tonic.value()) % SEMITONES_IN_OCTAVE;
+                profile[interval as usize]
+            });
+
+            match correlation(&distribution, &rotated) {
+                Some(score) => candidates.push((tonic, formula, score)),
+                None => return Vec::new(),
+            }
+        }
+    }
+
+    candidates.sort_by(|a, b| b.2.total_cmp(&a.2));
+    candidates
+}
+
+/// Builds a chroma vector: the total weight of each pitch class, indexed by pitch class value.
+///
+/// Weights are typically note durations or occurrence counts. Repeated pitch
+/// classes are summed. Use [`normalize_chroma`] to turn the result into a
+/// distribution.
+///
+/// # Examples
+/// ```
+/// use musik_std::{chroma_vector, C, E, G};
+///
+/// let chroma = chroma_vector(&[(C, 2.0), (E, 1.0), (G, 1.0), (C, 0.5)]);
+/// assert_eq!(chroma[0], 2.5);
+/// assert_eq!(chroma[4], 1.0);
+/// assert_eq!(chroma[1], 0.0);
+/// ```
+pub fn chroma_vector(notes: &[(PitchClass, f64)]) -> [f64; SEMITONES_IN_OCTAVE as usize] {
+    let mut chroma = [0.0; SEMITONES_IN_OCTAVE as usize];
+    for &(pitch_class, weight) in notes {
+        chroma[pitch_class.value() as usize] += weight;
+    }
+    chroma
+}
+
+/// Scales a chroma vector so that its entries sum to 1.
+///
+/// A vector whose entries sum to zero is returned unchanged.
+///
+/// # Examples
+/// ```
+/// use musik_std::{chroma_vector, normalize_chroma, C, G};
+///
+/// let chroma = normalize_chroma(chroma_vector(&[(C, 3.0), (G, 1.0)]));
+/// assert_eq!(chroma[0], 0.75);
+/// assert_eq!(chroma[7], 0.25);
+/// ```
+pub fn normalize_chroma(
+    chroma: [f64; SEMITONES_IN_OCTAVE as usize],
+) -> [f64; SEMITONES_IN_OCTAVE as usize] {
+    let total: f64 = chroma.iter().sum();
+    if total == 0.0 {
+        return chroma;
+    }
+    chroma.map(|weight| weight / total)
+}
+
+/// Pearson correlation coefficient, or `None` if either series is constant.
+fn correlation(xs: &[f64], ys: &[f64]) -> Option<f64> {
+    let count = xs.len() as f64;
+    let mean_x = xs.iter().sum::<f64>() / count;
+    let mean_y = ys.iter().sum::<f64>() / count;
+
+    let mut covariance = 0.0;
+    let mut variance_x = 0.0;
+    let mut variance_y = 0.0;
+    for (x, y) in xs.iter().zip(ys) {
+        let dx = x - mean_x;
+        let dy = y - mean_y;
+        covariance += dx * dy;
+        variance_x += dx * dx;
+        variance_y += dy * dy;
+    }
+
+    if variance_x == 0.0 || variance_y == 0.0 {
+        return None;
+    }
+
+    Some(covariance / (variance_x * variance_y).sqrt())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pitch_class::{A, B, C, C_SHARP, D, D_SHARP, E, F, F_SHARP, G};
+
+    #[test]
+    fn test_analyze_major_triad() {
+        match analyze(&[C, E, G]) {
+            AnalysisResult::Chord(candidates) => {
+                assert_eq!(candidates, vec![(C, ChordFormula::major_triad())]);
+            }
+            other => panic!("expected a chord, got {:?}", other),
+        }
+
+        // Order and duplicates do not matter for recognition
+        match analyze(&[G, E, C, G]) {
+            AnalysisResult::Chord(candidates) => {
+                assert_eq!(candidates, vec![(C, ChordFormula::major_triad())]);
+            }
+            other => panic!("expected a chord, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_analyze_ambiguous_chord() {
+        // C6 and Am7 share the same pitch classes
+        match analyze(&[C, E, G, A]) {
+            AnalysisResult::Chord(candidates) => {
+                assert_eq!(candidates.len(), 2);
+                assert_eq!(candidates[0], (C, ChordFormula::sixth()));
+                assert_eq!(candidates[1], (A, ChordFormula::minor_seventh()));
+            }
+            other => panic!("expected a chord, got {:?}", other),
+        }
+
+        // Fully diminished seventh is symmetric: every note is a possible root
+        match analyze(&[C, D_SHARP, F_SHARP, A]) {
+            AnalysisResult::Chord(candidates) => assert_eq!(candidates.len(), 4),
+            other => panic!("expected a chord, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_analyze_major_scale() {
+        match analyze(&[C, D, E, F, G, A, B]) {
+            AnalysisResult::Scale(candidates) => {
+                assert_eq!(candidates[0], (C, ScaleFormula::major()));
+                assert!(candidates.contains(&(A, ScaleFormula::minor())));
+                assert_eq!(candidates.len(), 2);
+            }
+            other => panic!("expected a scale, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_analyze_unknown() {
+        assert_eq!(analyze(&[C, C_SHARP, D]), AnalysisResult::Unknown);
+        assert_eq!(analyze(&[C, C_SHARP, F_SHARP, B]), AnalysisResult::Unknown);
+        assert_eq!(analyze(&[]), AnalysisResult::Unknown);
+    }
+
+    #[test]
+    fn test_detect_key_major() {
+        let counts = [(C, 10), (D, 4), (E, 7), (F, 4), (G, 8), (A, 4), (B, 3)];
+        let candidates = detect_key(&counts);
+
+        assert_eq!(candidates.len(), 24);
+        assert_eq!(candidates[0].0, C);
+        assert_eq!(candidates[0].1, ScaleFormula::major());
+        assert!(candidates[0].2 > 0.8);
+
+        // Ranked from best to worst
+        for pair in candidates.windows(2) {
+            assert!(pair[0].2 >= pair[1].2);
+        }
+    }
+
+    #[test]
+    fn test_detect_key_minor() {
+        use crate::pitch_class::{E_FLAT, G_SHARP};
+
+        // A harmonic minor melody emphasizing A and E
+        let counts = [
+            (A, 12),
+            (B, 3),
+            (C, 6),
+            (D, 4),
+            (E, 9),
+            (F, 3),
+            (G_SHARP, 4),
+        ];
+        let (tonic, formula, _) = detect_key(&counts)[0];
+        assert_eq!((tonic, formula), (A, ScaleFormula::minor()));
+
+        // Transposed: C minor
+        let counts = [
+            (C, 12),
+            (D, 3),
+            (E_FLAT, 6),
+            (F, 4),
+            (G, 9),
+            (G_SHARP, 3),
+            (B, 4),
+        ];
+        let (tonic, formula, _) = detect_key(&counts)[0];
+        assert_eq!((tonic, formula), (C, ScaleFormula::minor()));
+    }
+
+    #[test]
+    fn test_detect_key_duplicates_are_summed() {
+        let split = [(C, 5), (E, 7), (C, 5), (G, 8)];
+        let merged = [(C, 10), (E, 7), (G, 8)];
+        assert_eq!(detect_key(&split), detect_key(&merged));
+    }
+
+    #[test]
+    fn test_detect_key_without_information() {
+        assert!(detect_key(&[]).is_empty());
+
+        let uniform: Vec<(PitchClass, u32)> = PitchClass::all().map(|pc| (pc, 3)).collect();
+        assert!(detect_key(&uniform).is_empty());
+    }
+
+    #[test]
+    fn test_chroma_vector() {
+        let all_c = chroma_vector(&[(C, 1.0), (C, 2.0), (C, 0.5)]);
+        assert_eq!(all_c[0], 3.5);
+        assert!(all_c[1..].iter().all(|&weight| weight == 0.0));
+
+        let triad = chroma_vector(&[(C, 1.0), (E, 1.0), (G, 2.0)]);
+        assert_eq!(triad[C.value() as usize], 1.0);
+        assert_eq!(triad[E.value() as usize], 1.0);
+        assert_eq!(triad[G.value() as usize], 2.0);
+
+        assert_eq!(chroma_vector(&[]), [0.0; 12]);
+    }
+
+    #[test]
+    fn test_normalize_chroma() {
+        let all_c = normalize_chroma(chroma_vector(&[(C, 4.0), (C, 1.0)]));
+        assert_eq!(all_c[0], 1.0);
+
+        let mixed = normalize_chroma(chroma_vector(&[(C, 1.0), (D, 3.0), (A, 0.7), (B, 2.3)]));
+        assert!((mixed.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+        assert_eq!(mixed[D.value() as usize], 3.0 / 7.0);
+
+        // Nothing to normalize
+        assert_eq!(normalize_chroma([0.0; 12]), [0.0; 12]);
+    }
+}
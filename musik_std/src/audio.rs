@@ -0,0 +1,91 @@
+//! Audio utilities for working with frequencies.
+
+/// The number of cents in an octave.
+///
+/// A cent is 1/100 of an equal-tempered semitone, so an octave spans 1200 cents.
+///
+/// # Examples
+///
+/// ```
+/// use musik_std::CENTS_IN_OCTAVE;
+///
+/// assert_eq!(CENTS_IN_OCTAVE, 1200.0);
+/// ```
+pub const CENTS_IN_OCTAVE: f64 = 1200.0;
+
+/// The standard concert pitch frequency of A4 in Hz.
+///
+/// # Examples
+///
+/// ```
+/// use musik_std::A4_FREQUENCY;
+///
+/// assert_eq!(A4_FREQUENCY, 440.0);
+/// ```
+pub const A4_FREQUENCY: f64 = 440.0;
+
+/// Returns the interval from `f1` to `f2` in cents.
+///
+/// The result is `1200 * log2(f2 / f1)`: positive when `f2` is higher than `f1`,
+/// negative when it is lower.
+///
+/// # Examples
+///
+/// ```
+/// use musik_std::cents_between;
+///
+/// assert_eq!(cents_between(440.0, 880.0), 1200.0); // One octave up
+/// assert_eq!(cents_between(440.0, 220.0), -1200.0); // One octave down
+/// assert_eq!(cents_between(440.0, 440.0), 0.0); // Unison
+/// ```
+pub fn cents_between(f1: f64, f2: f64) -> f64 {
+    CENTS_IN_OCTAVE * (f2 / f1).log2()
+}
+
+/// Checks whether two frequencies are within `tol_cents` cents of each other.
+///
+/// # Examples
+///
+/// ```
+/// use musik_std::approx_equal_cents;
+///
+/// // 441 Hz is about 3.9 cents above 440 Hz
+/// assert!(approx_equal_cents(440.0, 441.0, 5.0));
+/// assert!(!approx_equal_cents(440.0, 441.0, 1.0));
+/// ```
+pub fn approx_equal_cents(f1: f64, f2: f64, tol_cents: f64) -> bool {
+    cents_between(f1, f2).abs() <= tol_cents
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cents_between_octaves() {
+        assert_eq!(cents_between(440.0, 880.0), 1200.0);
+        assert_eq!(cents_between(440.0, 220.0), -1200.0);
+        assert_eq!(cents_between(440.0, 1760.0), 2400.0);
+        assert_eq!(cents_between(440.0, 440.0), 0.0);
+    }
+
+    #[test]
+    fn test_cents_between_semitone() {
+        let semitone_up = 440.0 * 2f64.powf(1.0 / 12.0);
+        assert!((cents_between(440.0, semitone_up) - 100.0).abs() < 1e-9);
+
+        // Just perfect fifth (3:2) is about 702 cents
+        let just_fifth = cents_between(440.0, 660.0);
+        assert!((just_fifth - 701.955).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_approx_equal_cents() {
+        assert!(approx_equal_cents(440.0, 440.0, 0.0));
+        assert!(approx_equal_cents(440.0, 441.0, 5.0));
+        assert!(approx_equal_cents(441.0, 440.0, 5.0)); // Symmetric
+        assert!(!approx_equal_cents(440.0, 441.0, 1.0));
+        assert!(!approx_equal_cents(440.0, 880.0, 1199.0));
+        assert!(approx_equal_cents(440.0, 880.0, 1200.0));
+    }
+}
@@ -0,0 +1,26 @@
+//! Plain data representation of chord formulas.
+//!
+//! This module provides the `ChordDescriptor` struct, a stable shape for
+//! storing or serializing a `ChordFormula` independently of its bit packing.
+
+/// A chord formula as plain data.
+///
+/// Each degree is paired with its alteration as a semitone offset: `-1` for
+/// flat, `0` for natural, and `+1` for sharp. The packed bits are included for
+/// callers that want a compact form alongside the readable one.
+///
+/// # Examples
+/// ```
+/// use musik_std::{ChordDescriptor, ChordFormula};
+///
+/// let descriptor = ChordFormula::minor_seventh().to_descriptor();
+/// assert_eq!(descriptor.degrees, vec![(1, 0), (3, -1), (5, 0), (7, -1)]);
+/// assert_eq!(ChordFormula::from_descriptor(&descriptor), Some(ChordFormula::minor_seventh()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct ChordDescriptor {
+    /// The chord degrees (1-15) in ascending order, each with its alteration.
+    pub degrees: Vec<(u8, i8)>,
+    /// The packed bit pattern of the formula.
+    pub bits: u32,
+}
@@ -4,9 +4,126 @@
 //! using a compact bit-packed format. Each degree in the major scale uses 2 bits
 //! to encode whether it's absent, natural, flat, or sharp in the chord.
 
+use crate::chord_descriptor::ChordDescriptor;
 use crate::degree_alteration::DegreeAlteration;
+use crate::formula_degree::FormulaDegree;
+use crate::harmony::TRITONE;
+use crate::note::Note;
+use crate::note_role::NoteRole;
+use crate::pitch_class::PitchClass;
+use crate::scale_formula::ScaleFormula;
+use crate::semitone::SEMITONES_IN_OCTAVE;
 use std::fmt;
 
+/// Scale formulas searched by [`ChordFormula::compatible_scales`].
+const COMPATIBLE_SCALES: [ScaleFormula; 10] = [
+    ScaleFormula::major(),
+    ScaleFormula::dorian(),
+    ScaleFormula::phrygian(),
+    ScaleFormula::lydian(),
+    ScaleFormula::mixolydian(),
+    ScaleFormula::minor(),
+    ScaleFormula::locrian(),
+    ScaleFormula::pentatonic_major(),
+    ScaleFormula::pentatonic_minor(),
+    ScaleFormula::blues(),
+];
+
+/// Dissonance weight of each interval class (0 to 6 semitones), used by
+/// [`ChordFormula::dissonance_score`].
+const INTERVAL_CLASS_DISSONANCE: [f64; 7] = [
+    0.0, // Unison / octave: perfect consonance
+    1.0, // Minor 2nd / major 7th: sharp dissonance
+    0.5, // Major 2nd / minor 7th: mild dissonance
+    0.1, // Minor 3rd / major 6th: imperfect consonance
+    0.1, // Major 3rd / minor 6th: imperfect consonance
+    0.0, // Perfect 4th / perfect 5th: perfect consonance
+    0.8, // Tritone
+];
+
+/// Middle C (C4), the register [`ChordFormula::minimal_motion_to`] voices chords around.
+const MIDDLE_C: Note = Note::new(60);
+
+/// Canonical chord scale for each chord type, used by [`ChordFormula::primary_scale`].
+const PRIMARY_SCALES: [(ChordFormula, ScaleFormula); 16] = [
+    (ChordFormula::major_seventh(), ScaleFormula::major()),
+    (ChordFormula::major_ninth(), ScaleFormula::major()),
+    (ChordFormula::major_thirteenth(), ScaleFormula::major()),
+    (ChordFormula::dominant_seventh(), ScaleFormula::mixolydian()),
+    (ChordFormula::dominant_ninth(), ScaleFormula::mixolydian()),
+    (
+        ChordFormula::dominant_eleventh(),
+        ScaleFormula::mixolydian(),
+    ),
+    (
+        ChordFormula::dominant_thirteenth(),
+        ScaleFormula::mixolydian(),
+    ),
+    (ChordFormula::minor_seventh(), ScaleFormula::dorian()),
+    (ChordFormula::minor_ninth(), ScaleFormula::dorian()),
+    (ChordFormula::minor_eleventh(), ScaleFormula::dorian()),
+    (ChordFormula::minor_thirteenth(), ScaleFormula::dorian()),
+    (
+        ChordFormula::half_diminished_seventh(),
+        ScaleFormula::locrian(),
+    ),
+    (
+        ChordFormula::fully_diminished_seventh(),
+        ScaleFormula::octatonic_wh(),
+    ),
+    (
+        ChordFormula::dominant_seventh_flat_ninth(),
+        ScaleFormula::octatonic_hw(),
+    ),
+    (
+        ChordFormula::dominant_thirteenth_flat_ninth(),
+        ScaleFormula::octatonic_hw(),
+    ),
+    (ChordFormula::altered_dominant(), ScaleFormula::altered()),
+];
+
+/// Built-in chord formulas with their canonical symbol suffixes, used by
+/// [`ChordFormula::catalog`], [`ChordFormula::from_symbol`], and chord recognition.
+pub(crate) const CATALOG: [(&str, ChordFormula); 37] = [
+    ("", ChordFormula::major_triad()),
+    ("m", ChordFormula::minor_triad()),
+    ("dim", ChordFormula::diminished_triad()),
+    ("aug", ChordFormula::augmented_triad()),
+    ("sus2", ChordFormula::sus2()),
+    ("sus4", ChordFormula::sus4()),
+    ("maj7", ChordFormula::major_seventh()),
+    ("m7", ChordFormula::minor_seventh()),
+    ("7", ChordFormula::dominant_seventh()),
+    ("mMaj7", ChordFormula::minor_major_seventh()),
+    ("m7b5", ChordFormula::half_diminished_seventh()),
+    ("dim7", ChordFormula::fully_diminished_seventh()),
+    ("maj7#5", ChordFormula::augmented_major_seventh()),
+    ("aug7", ChordFormula::augmented_seventh()),
+    ("maj9", ChordFormula::major_ninth()),
+    ("m9", ChordFormula::minor_ninth()),
+    ("9", ChordFormula::dominant_ninth()),
+    ("7b9", ChordFormula::dominant_seventh_flat_ninth()),
+    ("7#9", ChordFormula::dominant_seventh_sharp_ninth()),
+    ("maj11", ChordFormula::major_eleventh()),
+    ("m11", ChordFormula::minor_eleventh()),
+    ("11", ChordFormula::dominant_eleventh()),
+    ("7#11", ChordFormula::dominant_seventh_sharp_eleventh()),
+    ("maj13", ChordFormula::major_thirteenth()),
+    ("m13", ChordFormula::minor_thirteenth()),
+    ("13", ChordFormula::dominant_thirteenth()),
+    ("13b9", ChordFormula::dominant_thirteenth_flat_ninth()),
+    ("13#11", ChordFormula::dominant_thirteenth_sharp_eleventh()),
+    ("add9", ChordFormula::add_ninth()),
+    ("madd9", ChordFormula::minor_add_ninth()),
+    ("6", ChordFormula::sixth()),
+    ("m6", ChordFormula::minor_sixth()),
+    ("6/9", ChordFormula::six_nine()),
+    ("m6/9", ChordFormula::minor_six_nine()),
+    ("alt", ChordFormula::altered_dominant()),
+    ("7#5", ChordFormula::dominant_seventh_sharp_fifth()),
+    ("7b5", ChordFormula::dominant_seventh_flat_fifth()),
+];
+
 /// Represents a chord formula using bit-packed degree information.
 ///
 /// Uses a `u32` to store chord degree information with 2 bits per degree:
@@ -145,6 +262,25 @@ impl ChordFormula {
         ChordFormula(new_bits)
     }
 
+    /// Removes a degree, in any alteration, from the chord formula.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{ChordFormula, DegreeAlteration};
+    ///
+    /// let power_chord = ChordFormula::major_triad().without_degree(3);
+    /// assert!(!power_chord.has_any_degree(3));
+    /// assert!(power_chord.has_degree(5, DegreeAlteration::None));
+    /// ```
+    pub const fn without_degree(self, degree: u8) -> Self {
+        if degree == 0 || degree > 15 {
+            return self;
+        }
+
+        let shift = (degree - 1) * 2;
+        ChordFormula(self.0 & !(0b11 << shift))
+    }
+
     /// Checks if a specific degree with alteration is present in the chord formula.
     ///
     /// # Arguments
@@ -166,108 +302,970 @@ impl ChordFormula {
             return false;
         }
 
-        let expected = alteration.to_u8() as u32;
+        let expected = alteration.to_u8() as u32;
+
+        let shift = (degree - 1) * 2;
+        let actual_value = (self.0 >> shift) & 0b11;
+
+        actual_value == expected
+    }
+
+    /// Checks if any form of a degree (natural, flat, or sharp) is in the chord.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ChordFormula;
+    ///
+    /// let minor_triad = ChordFormula::minor_triad();
+    /// assert!(minor_triad.has_any_degree(3)); // Has flat 3rd
+    /// assert!(!minor_triad.has_any_degree(7)); // No 7th
+    /// ```
+    pub const fn has_any_degree(&self, degree: u8) -> bool {
+        if degree == 0 || degree > 15 {
+            return false;
+        }
+
+        let shift = (degree - 1) * 2;
+        let value = (self.0 >> shift) & 0b11;
+
+        value != 0
+    }
+
+    /// Gets the alteration of a degree if it exists in the chord.
+    ///
+    /// Returns `None` if the degree is not in the chord, or `Some(Option<DegreeAlteration>)`
+    /// where the inner `Option` is `None` for natural and `Some` for altered degrees.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{ChordFormula, DegreeAlteration};
+    ///
+    /// let minor_triad = ChordFormula::minor_triad();
+    /// assert_eq!(minor_triad.get_degree_alteration(1), Some(DegreeAlteration::None)); // Natural root
+    /// assert_eq!(minor_triad.get_degree_alteration(3), Some(DegreeAlteration::Flat)); // Flat 3rd
+    /// assert_eq!(minor_triad.get_degree_alteration(7), None); // No 7th
+    /// ```
+    pub const fn get_degree_alteration(&self, degree: u8) -> Option<DegreeAlteration> {
+        if degree == 0 || degree > 15 {
+            return None;
+        }
+
+        let shift = (degree - 1) * 2;
+        let value = (self.0 >> shift) & 0b11;
+
+        match value {
+            0 => None,                                                         // Not in chord
+            v if v >= 1 && v <= 3 => Some(DegreeAlteration::from_u8(v as u8)), // Natural, Flat, or Sharp
+            _ => None, // Should never happen
+        }
+    }
+
+    /// Returns a list of all degrees present in the chord with their alterations.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{ChordFormula, DegreeAlteration};
+    ///
+    /// let minor_seventh = ChordFormula::minor_seventh();
+    /// let degrees = minor_seventh.degrees();
+    ///
+    /// assert_eq!(degrees.len(), 4);
+    /// assert!(degrees.contains(&(1, DegreeAlteration::None)));
+    /// assert!(degrees.contains(&(3, DegreeAlteration::Flat)));
+    /// assert!(degrees.contains(&(5, DegreeAlteration::None)));
+    /// assert!(degrees.contains(&(7, DegreeAlteration::Flat)));
+    /// ```
+    pub fn degrees(&self) -> Vec<(u8, DegreeAlteration)> {
+        let mut result = Vec::new();
+
+        for degree in 1..=15 {
+            if let Some(alteration) = self.get_degree_alteration(degree) {
+                result.push((degree, alteration));
+            }
+        }
+
+        result
+    }
+
+    /// Converts the chord to a plain [`ChordDescriptor`].
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ChordFormula;
+    ///
+    /// let descriptor = ChordFormula::dominant_seventh_sharp_ninth().to_descriptor();
+    /// assert_eq!(descriptor.degrees, vec![(1, 0), (3, 0), (5, 0), (7, -1), (9, 1)]);
+    /// assert_eq!(descriptor.bits, ChordFormula::dominant_seventh_sharp_ninth().bits());
+    /// ```
+    pub fn to_descriptor(&self) -> ChordDescriptor {
+        ChordDescriptor {
+            degrees: self
+                .degrees()
+                .into_iter()
+                .map(|(degree, alteration)| (degree, alteration.semitone_offset()))
+                .collect(),
+            bits: self.0,
+        }
+    }
+
+    /// Builds a chord from a [`ChordDescriptor`].
+    ///
+    /// The chord is built from the degree list. Returns `None` if a degree is
+    /// outside 1-15, an alteration is not -1, 0, or +1, or the degrees do not
+    /// match the descriptor's bits.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{ChordDescriptor, ChordFormula};
+    ///
+    /// let sus4 = ChordFormula::sus4();
+    /// assert_eq!(ChordFormula::from_descriptor(&sus4.to_descriptor()), Some(sus4));
+    ///
+    /// let bad = ChordDescriptor { degrees: vec![(1, 0), (3, 2)], bits: 0 };
+    /// assert_eq!(ChordFormula::from_descriptor(&bad), None);
+    /// ```
+    pub fn from_descriptor(descriptor: &ChordDescriptor) -> Option<ChordFormula> {
+        let mut formula = ChordFormula::empty();
+        for &(degree, offset) in &descriptor.degrees {
+            if !(1..=15).contains(&degree) {
+                return None;
+            }
+            let alteration = match offset {
+                -1 => DegreeAlteration::Flat,
+                0 => DegreeAlteration::None,
+                1 => DegreeAlteration::Sharp,
+                _ => return None,
+            };
+            formula = formula.with_degree(degree, alteration);
+        }
+
+        (formula.0 == descriptor.bits).then_some(formula)
+    }
+
+    /// Counts the degrees present in the chord without allocating.
+    ///
+    /// Equivalent to `degrees().len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ChordFormula;
+    ///
+    /// assert_eq!(ChordFormula::major_triad().degree_count(), 3);
+    /// assert_eq!(ChordFormula::major_thirteenth().degree_count(), 7);
+    /// ```
+    pub const fn degree_count(&self) -> u8 {
+        let mut count = 0;
+        let mut shift = 0;
+
+        while shift < 30 {
+            if (self.0 >> shift) & 0b11 != 0 {
+                count += 1;
+            }
+            shift += 2;
+        }
+
+        count
+    }
+
+    /// Combines two chord formulas using bitwise OR.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{ChordFormula, DegreeAlteration};
+    ///
+    /// let triad = ChordFormula::major_triad();
+    /// let seventh = ChordFormula::empty().with_degree(7, DegreeAlteration::None);
+    /// let major_seventh = triad.union(seventh);
+    ///
+    /// assert!(major_seventh.has_degree(1, DegreeAlteration::None));
+    /// assert!(major_seventh.has_degree(3, DegreeAlteration::None));
+    /// assert!(major_seventh.has_degree(5, DegreeAlteration::None));
+    /// assert!(major_seventh.has_degree(7, DegreeAlteration::None));
+    /// ```
+    pub const fn union(self, other: ChordFormula) -> Self {
+        ChordFormula(self.0 | other.0)
+    }
+
+    /// Adds a major 7th to the chord.
+    ///
+    /// Degrees are relative to the major scale, so this adds the natural 7th; use
+    /// `with_degree(7, DegreeAlteration::Flat)` for a dominant or minor 7th.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ChordFormula;
+    ///
+    /// assert_eq!(ChordFormula::major_triad().with_seventh(), ChordFormula::major_seventh());
+    /// ```
+    pub const fn with_seventh(self) -> Self {
+        self.with_degree(7, DegreeAlteration::None)
+    }
+
+    /// Adds a major 9th to the chord.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ChordFormula;
+    ///
+    /// let chord = ChordFormula::major_triad().with_seventh().with_ninth();
+    /// assert_eq!(chord, ChordFormula::major_ninth());
+    /// ```
+    pub const fn with_ninth(self) -> Self {
+        self.with_degree(9, DegreeAlteration::None)
+    }
+
+    /// Lowers the 5th of the chord by a semitone.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ChordFormula;
+    ///
+    /// assert_eq!(ChordFormula::minor_triad().flat_five(), ChordFormula::diminished_triad());
+    /// ```
+    pub const fn flat_five(self) -> Self {
+        self.with_degree(5, DegreeAlteration::Flat)
+    }
+
+    /// Raises the 5th of the chord by a semitone.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ChordFormula;
+    ///
+    /// assert_eq!(ChordFormula::major_triad().sharp_five(), ChordFormula::augmented_triad());
+    /// ```
+    pub const fn sharp_five(self) -> Self {
+        self.with_degree(5, DegreeAlteration::Sharp)
+    }
+
+    /// Suspends the chord, replacing its 3rd with the given degree (usually 2 or 4).
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ChordFormula;
+    ///
+    /// assert_eq!(ChordFormula::major_triad().sus(4), ChordFormula::sus4());
+    /// assert_eq!(ChordFormula::minor_triad().sus(2), ChordFormula::sus2());
+    /// ```
+    pub const fn sus(self, degree: u8) -> Self {
+        self.without_degree(3)
+            .with_degree(degree, DegreeAlteration::None)
+    }
+
+    /// Removes the root, as in rootless voicings where the bass covers it.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{ChordFormula, DegreeAlteration};
+    ///
+    /// let rootless = ChordFormula::major_triad().without_root();
+    /// assert_eq!(rootless.degrees(), vec![(3, DegreeAlteration::None), (5, DegreeAlteration::None)]);
+    /// ```
+    pub const fn without_root(self) -> Self {
+        self.without_degree(1)
+    }
+
+    /// Removes the 5th, in any alteration.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ChordFormula;
+    ///
+    /// let ninth = ChordFormula::dominant_ninth().without_fifth();
+    /// assert!(!ninth.has_any_degree(5));
+    /// assert_eq!(ninth.degree_count(), 4);
+    /// ```
+    pub const fn without_fifth(self) -> Self {
+        self.without_degree(5)
+    }
+
+    /// Reduces the chord to its shell: the 3rd and 7th, in their alterations.
+    ///
+    /// Degrees the chord does not have are left out, so the shell of a triad
+    /// is just its 3rd.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{ChordFormula, DegreeAlteration};
+    ///
+    /// let shell = ChordFormula::dominant_thirteenth().shell_voicing();
+    /// assert_eq!(shell.degrees(), vec![(3, DegreeAlteration::None), (7, DegreeAlteration::Flat)]);
+    /// ```
+    pub const fn shell_voicing(self) -> Self {
+        let third = 0b11 << ((3 - 1) * 2);
+        let seventh = 0b11 << ((7 - 1) * 2);
+        ChordFormula(self.0 & (third | seventh))
+    }
+
+    /// Voices the chord from `root` so that every note clusters around `target`.
+    ///
+    /// Each chord tone is placed in the octave that brings it closest to the
+    /// target note, so the whole voicing stays within an octave around it.
+    /// The returned notes are sorted from lowest to highest and free of duplicates.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{ChordFormula, Note, C};
+    ///
+    /// let c_major = ChordFormula::major_triad();
+    ///
+    /// // G3, C4, E4 around middle C
+    /// let voicing = c_major.voice_near(C, Note::new(60));
+    /// assert_eq!(voicing, vec![Note::new(55), Note::new(60), Note::new(64)]);
+    ///
+    /// // The same voicing one octave higher
+    /// let voicing = c_major.voice_near(C, Note::new(72));
+    /// assert_eq!(voicing, vec![Note::new(67), Note::new(72), Note::new(76)]);
+    /// ```
+    pub fn voice_near(&self, root: PitchClass, target: Note) -> Vec<Note> {
+        let octave = SEMITONES_IN_OCTAVE as i16;
+        let target = target.semitone() as i16;
+
+        let mut notes: Vec<Note> = self
+            .semitone_offsets()
+            .into_iter()
+            .filter_map(|offset| {
+                let pitch_class = (root.value() + offset) as i16;
+                let below = target - (target - pitch_class).rem_euclid(octave);
+                let above = below + octave;
+                let nearest = if target - below <= above - target {
+                    below
+                } else {
+                    above
+                };
+
+                [nearest, below, above]
+                    .into_iter()
+                    .find(|candidate| (0..=u8::MAX as i16).contains(candidate))
+                    .map(|candidate| Note::new(candidate as u8))
+            })
+            .collect();
+
+        notes.sort();
+        notes.dedup();
+        notes
+    }
+
+    /// Suggests the smoothest voice leading from this chord to `other`.
+    ///
+    /// This chord is voiced around middle C with [`voice_near`](Self::voice_near),
+    /// and each of its notes is paired with the nearest note of `other`. Common
+    /// tones stay in place. Voices with the smallest moves are assigned first,
+    /// and when two targets are equally near, a voice prefers a tone of `other`
+    /// that no earlier voice has taken. The pairs are sorted by their starting note.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{ChordFormula, Note, C, F};
+    ///
+    /// let major = ChordFormula::major_triad();
+    /// let motion = major.minimal_motion_to(C, &major, F);
+    /// assert_eq!(
+    ///     motion,
+    ///     vec![
+    ///         (Note::new(55), Note::new(57)), // G3 up to A3
+    ///         (Note::new(60), Note::new(60)), // C4 is held
+    ///         (Note::new(64), Note::new(65)), // E4 up to F4
+    ///     ]
+    /// );
+    /// ```
+    pub fn minimal_motion_to(
+        &self,
+        self_root: PitchClass,
+        other: &ChordFormula,
+        other_root: PitchClass,
+    ) -> Vec<(Note, Note)> {
+        let targets = |from: Note| other.voice_near(other_root, from);
+        let distance = |from: Note, to: Note| from.semitone().abs_diff(to.semitone());
+
+        let mut voices = self.voice_near(self_root, MIDDLE_C);
+        voices.sort_by_key(|&from| targets(from).into_iter().map(|to| distance(from, to)).min());
+
+        let mut taken: Vec<PitchClass> = Vec::new();
+        let mut motion: Vec<(Note, Note)> = voices
+            .into_iter()
+            .filter_map(|from| {
+                let to = targets(from)
+                    .into_iter()
+                    .min_by_key(|&to| (distance(from, to), taken.contains(&to.pitch_class())))?;
+                taken.push(to.pitch_class());
+                Some((from, to))
+            })
+            .collect();
+
+        motion.sort();
+        motion
+    }
+
+    /// Voices the chord from `root` in root position and in every inversion.
+    ///
+    /// The root position stacks each degree above `root`, keeping extensions in
+    /// their upper octave. Each following voicing moves the bottom note of the
+    /// previous one up an octave, so a chord with `n` notes yields `n` voicings.
+    /// Voicings that would exceed the `Note` range are left out. Since every
+    /// inversion keeps the top note of the root position and only raises the
+    /// others, once a voicing overflows so do all later ones, and a root position
+    /// that overflows yields no voicings at all.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{ChordFormula, Note};
+    ///
+    /// let inversions = ChordFormula::major_triad().all_inversions(Note::new(60));
+    /// assert_eq!(
+    ///     inversions,
+    ///     vec![
+    ///         vec![Note::new(60), Note::new(64), Note::new(67)], // C4 E4 G4
+    ///         vec![Note::new(64), Note::new(67), Note::new(72)], // E4 G4 C5
+    ///         vec![Note::new(67), Note::new(72), Note::new(76)], // G4 C5 E5
+    ///     ]
+    /// );
+    /// ```
+    pub fn all_inversions(&self, root: Note) -> Vec<Vec<Note>> {
+        let Some(root_position) = self
+            .formula_degrees()
+            .iter()
+            .map(|degree| degree.note_from_extended(root))
+            .collect::<Option<Vec<Note>>>()
+        else {
+            return Vec::new();
+        };
+        if root_position.is_empty() {
+            return Vec::new();
+        }
+
+        let mut inversions = vec![root_position];
+        for _ in 1..inversions[0].len() {
+            let mut voicing = inversions[inversions.len() - 1].clone();
+            let bottom = voicing.remove(0);
+            match bottom.semitone().checked_add(SEMITONES_IN_OCTAVE) {
+                Some(raised) => voicing.push(Note::new(raised)),
+                None => break,
+            }
+            inversions.push(voicing);
+        }
+
+        inversions
+    }
+
+    /// Returns the equal-tempered frequency in Hz (A4 = 440 Hz) of each note of
+    /// the chord voiced in root position from `root`.
+    ///
+    /// Extensions are kept in their upper octave, so the frequencies ascend.
+    /// Notes that would exceed the `Note` range are left out.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{ChordFormula, Note};
+    ///
+    /// let frequencies = ChordFormula::major_triad().frequencies(Note::new(60)); // C4
+    /// assert_eq!(frequencies.len(), 3);
+    /// assert!((frequencies[2] / frequencies[0] - 1.5).abs() < 0.01); // G4 is a fifth above
+    /// ```
+    pub fn frequencies(&self, root: Note) -> Vec<f64> {
+        self.formula_degrees()
+            .iter()
+            .filter_map(|degree| degree.note_from_extended(root))
+            .map(Note::frequency)
+            .collect()
+    }
+
+    /// Detects the inversion of a voicing of the chord built on `root`.
+    ///
+    /// The inversion is the position of the bass (lowest) note among the chord
+    /// tones in degree order: 0 for root position, 1 when the 3rd is in the bass,
+    /// 2 for the 5th, and so on. Returns `None` for an empty voicing or when the
+    /// bass is not a chord tone.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{ChordFormula, Note, C};
+    ///
+    /// let c_major = ChordFormula::major_triad();
+    /// let first = [Note::new(64), Note::new(67), Note::new(72)]; // E4 G4 C5
+    /// let second = [Note::new(55), Note::new(60), Note::new(64)]; // G3 C4 E4
+    ///
+    /// assert_eq!(c_major.detect_inversion(C, &first), Some(1));
+    /// assert_eq!(c_major.detect_inversion(C, &second), Some(2));
+    /// ```
+    pub fn detect_inversion(&self, root: PitchClass, voicing: &[Note]) -> Option<u8> {
+        let bass = voicing.iter().min()?.pitch_class();
+        self.pitch_classes(root)
+            .iter()
+            .position(|&pitch_class| pitch_class == bass)
+            .map(|position| position as u8)
+    }
+
+    /// Returns a list of all degrees present in the chord as [`FormulaDegree`] values.
+    ///
+    /// This is the same information as [`degrees`](Self::degrees), expressed in the
+    /// representation used by the rest of the harmony API.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{ChordFormula, FormulaDegree};
+    ///
+    /// let degrees = ChordFormula::minor_seventh().formula_degrees();
+    /// assert_eq!(
+    ///     degrees,
+    ///     vec![
+    ///         FormulaDegree::natural(1),
+    ///         FormulaDegree::flat(3),
+    ///         FormulaDegree::natural(5),
+    ///         FormulaDegree::flat(7),
+    ///     ]
+    /// );
+    /// ```
+    pub fn formula_degrees(&self) -> Vec<FormulaDegree> {
+        self.degrees()
+            .into_iter()
+            .map(|(degree, alteration)| match alteration {
+                DegreeAlteration::None => FormulaDegree::natural(degree),
+                DegreeAlteration::Flat => FormulaDegree::flat(degree),
+                DegreeAlteration::Sharp => FormulaDegree::sharp(degree),
+            })
+            .collect()
+    }
+
+    /// Builds a chord formula from a list of [`FormulaDegree`] values.
+    ///
+    /// This is the inverse of [`formula_degrees`](Self::formula_degrees). Degrees
+    /// outside the supported range (1-15) are ignored, and a later entry for the
+    /// same degree replaces an earlier one.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{ChordFormula, FormulaDegree};
+    ///
+    /// let formula = ChordFormula::from_formula_degrees(&[
+    ///     FormulaDegree::natural(1),
+    ///     FormulaDegree::natural(3),
+    ///     FormulaDegree::natural(5),
+    ///     FormulaDegree::flat(7),
+    ///     FormulaDegree::flat(9),
+    /// ]);
+    /// assert_eq!(formula, ChordFormula::dominant_seventh_flat_ninth());
+    /// ```
+    pub fn from_formula_degrees(degrees: &[FormulaDegree]) -> Self {
+        degrees
+            .iter()
+            .fold(ChordFormula::empty(), |formula, formula_degree| {
+                let alteration = match formula_degree {
+                    FormulaDegree::Natural(_) => DegreeAlteration::None,
+                    FormulaDegree::Flat(_) => DegreeAlteration::Flat,
+                    FormulaDegree::Sharp(_) => DegreeAlteration::Sharp,
+                };
+                formula.with_degree(formula_degree.base_degree(), alteration)
+            })
+    }
+
+    /// Respells altered degrees so that no two chord tones share a letter name.
+    ///
+    /// Degrees a compound interval apart (such as 3 and 10, or 5 and 12) land on
+    /// the same letter. When an altered degree collides with another chord tone,
+    /// it is moved to its enharmonic neighbour on a free letter: a sharp becomes
+    /// the flat of the next degree, a flat becomes the sharp of the previous one.
+    /// The pitch classes of the chord are never changed; collisions without an
+    /// enharmonic alternative are left as they are.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{ChordFormula, DegreeAlteration};
+    ///
+    /// // A 7♯9 written with a ♭10 has both E and E♭ above C
+    /// let misspelled = ChordFormula::dominant_seventh().with_degree(10, DegreeAlteration::Flat);
+    /// assert_eq!(misspelled.normalize_spelling(), ChordFormula::dominant_seventh_sharp_ninth());
+    ///
+    /// // A ♭12 next to the natural 5th reads as ♯11
+    /// let misspelled = ChordFormula::dominant_seventh().with_degree(12, DegreeAlteration::Flat);
+    /// assert_eq!(misspelled.normalize_spelling(), ChordFormula::dominant_seventh_sharp_eleventh());
+    /// ```
+    pub fn normalize_spelling(&self) -> ChordFormula {
+        let letter = |degree: u8| (degree - 1) % 7;
+
+        let mut formula = *self;
+        for current in self.formula_degrees().into_iter().rev() {
+            let degree = current.base_degree();
+            let collides = formula.formula_degrees().iter().any(|other| {
+                other.base_degree() != degree && letter(other.base_degree()) == letter(degree)
+            });
+            if !collides {
+                continue;
+            }
+
+            let respelled = match current {
+                FormulaDegree::Sharp(d) if d < 15 => FormulaDegree::flat(d + 1),
+                FormulaDegree::Flat(d) if d > 1 => FormulaDegree::sharp(d - 1),
+                _ => continue,
+            };
+
+            let target = respelled.base_degree();
+            let target_free = formula
+                .formula_degrees()
+                .iter()
+                .all(|other| letter(other.base_degree()) != letter(target));
+            if !target_free || respelled.to_semitone_offset() != current.to_semitone_offset() {
+                continue;
+            }
+
+            let alteration = match respelled {
+                FormulaDegree::Flat(_) => DegreeAlteration::Flat,
+                _ => DegreeAlteration::Sharp,
+            };
+            formula = ChordFormula(formula.0 & !(0b11 << ((degree - 1) * 2)))
+                .with_degree(target, alteration);
+        }
+
+        formula
+    }
+
+    /// Suggests scales that contain every tone of the chord built on `root`.
+    ///
+    /// Searches the major modes (Ionian through Locrian), the major and minor
+    /// pentatonics, and the blues scale on all 12 roots, and returns every
+    /// `(scale root, formula)` pair whose pitch classes include the chord tones.
+    /// Scales are listed by root, starting from the chord root and moving up
+    /// chromatically, and in the order above for each root.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{ChordFormula, ScaleFormula, C, F};
+    ///
+    /// let scales = ChordFormula::dominant_seventh().compatible_scales(C);
+    /// assert_eq!(scales[0], (C, ScaleFormula::mixolydian()));
+    /// assert!(scales.contains(&(F, ScaleFormula::major())));
+    /// assert!(!scales.contains(&(C, ScaleFormula::major()))); // B clashes with B♭
+    /// ```
+    pub fn compatible_scales(&self, root: PitchClass) -> Vec<(PitchClass, ScaleFormula)> {
+        let chord_tones: Vec<PitchClass> = self
+            .semitone_offsets()
+            .into_iter()
+            .map(|offset| PitchClass::new(root.value() + offset))
+            .collect();
+
+        (0..SEMITONES_IN_OCTAVE)
+            .map(|step| PitchClass::new(root.value() + step))
+            .flat_map(|scale_root| {
+                COMPATIBLE_SCALES
+                    .iter()
+                    .filter(|scale| {
+                        chord_tones
+                            .iter()
+                            .all(|&tone| scale.contains_pitch_class(scale_root, tone))
+                    })
+                    .map(move |&scale| (scale_root, scale))
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// Returns the canonical chord scale for the chord built on `root`, as used in
+    /// chord-scale theory.
+    ///
+    /// Major sevenths map to Ionian, dominant sevenths to Mixolydian, minor
+    /// sevenths to Dorian, half-diminished sevenths to Locrian, and altered
+    /// dominants to the altered scale; their 9th, 11th, and 13th extensions
+    /// follow the same parent. Diminished sevenths take the whole-half
+    /// octatonic scale and 7♭9 chords the half-whole one. The scale is always
+    /// rooted on the chord root. Returns `None` for any other chord type.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{ChordFormula, ScaleFormula, D, G};
+    ///
+    /// assert_eq!(
+    ///     ChordFormula::minor_seventh().primary_scale(D),
+    ///     Some((D, ScaleFormula::dorian()))
+    /// );
+    /// assert_eq!(
+    ///     ChordFormula::altered_dominant().primary_scale(G),
+    ///     Some((G, ScaleFormula::altered()))
+    /// );
+    /// assert_eq!(ChordFormula::major_triad().primary_scale(G), None);
+    /// ```
+    pub fn primary_scale(&self, root: PitchClass) -> Option<(PitchClass, ScaleFormula)> {
+        PRIMARY_SCALES
+            .iter()
+            .find(|(chord, _)| chord == self)
+            .map(|&(_, scale)| (root, scale))
+    }
+
+    /// Scores how dissonant the chord is by summing a weight for every pair of its pitch classes.
+    ///
+    /// Each pair is reduced to its interval class, so an interval and its
+    /// inversion weigh the same. Perfect consonances weigh 0.0, thirds and
+    /// sixths 0.1, major 2nds and minor 7ths 0.5, the tritone 0.8, and minor
+    /// 2nds and major 7ths 1.0. Tones that fold to the same pitch class count
+    /// once, and an empty formula scores 0.0.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ChordFormula;
+    ///
+    /// let major = ChordFormula::major_triad().dissonance_score();
+    /// let flat_nine = ChordFormula::dominant_seventh_flat_ninth().dissonance_score();
+    /// assert!((major - 0.2).abs() < 1e-12); // Two thirds and a fifth
+    /// assert!(major < flat_nine);
+    /// ```
+    pub fn dissonance_score(&self) -> f64 {
+        let octave = SEMITONES_IN_OCTAVE as usize;
+        let pitch_classes: Vec<usize> = self
+            .pitch_classes(PitchClass::new(0))
+            .iter()
+            .map(|pitch_class| pitch_class.value() as usize)
+            .collect();
+
+        pitch_classes
+            .iter()
+            .enumerate()
+            .flat_map(|(index, &low)| {
+                pitch_classes[index + 1..].iter().map(move |&high| {
+                    let interval = (high + octave - low) % octave;
+                    INTERVAL_CLASS_DISSONANCE[interval.min(octave - interval)]
+                })
+            })
+            .sum()
+    }
+
+    /// Classifies a note by its harmonic role over the chord built on `root`.
+    ///
+    /// A note that belongs to the chord is a [`NoteRole::ChordTone`]. Any other
+    /// note a semitone above a chord tone is a [`NoteRole::Avoid`] note, except for
+    /// the ♭9 and ♭13 over a dominant chord (major 3rd and minor 7th), which are
+    /// the usual altered tensions. Every remaining note is a [`NoteRole::Tension`].
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{ChordFormula, NoteRole, C, C_SHARP, E, F};
+    ///
+    /// let major_seventh = ChordFormula::major_seventh();
+    /// assert_eq!(major_seventh.classify_note(C, E), NoteRole::ChordTone);
+    /// assert_eq!(major_seventh.classify_note(C, F), NoteRole::Avoid); // Clashes with E
+    ///
+    /// let dominant = ChordFormula::dominant_seventh();
+    /// assert_eq!(dominant.classify_note(C, C_SHARP), NoteRole::Tension); // ♭9
+    /// ```
+    pub fn classify_note(&self, root: PitchClass, note: PitchClass) -> NoteRole {
+        let offsets = self.semitone_offsets();
+        let interval = (note.value() + SEMITONES_IN_OCTAVE - root.value()) % SEMITONES_IN_OCTAVE;
+
+        if offsets.contains(&interval) {
+            return NoteRole::ChordTone;
+        }
+
+        let is_dominant = offsets.contains(&4) && offsets.contains(&10);
+        let is_altered_tension = is_dominant && (interval == 1 || interval == 8);
+        let below = (interval + SEMITONES_IN_OCTAVE - 1) % SEMITONES_IN_OCTAVE;
+
+        if offsets.contains(&below) && !is_altered_tension {
+            NoteRole::Avoid
+        } else {
+            NoteRole::Tension
+        }
+    }
+
+    /// Returns every built-in chord formula paired with its canonical symbol suffix.
+    ///
+    /// The suffix is what follows the root in a chord symbol: `""` for a major
+    /// triad, `"m"` for minor, `"maj7"`, `"m7b5"`, and so on. Accidentals are
+    /// written as `#` and `b`.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ChordFormula;
+    ///
+    /// let catalog = ChordFormula::catalog();
+    /// assert!(catalog.contains(&("", ChordFormula::major_triad())));
+    /// assert!(catalog.contains(&("m7", ChordFormula::minor_seventh())));
+    /// ```
+    pub fn catalog() -> Vec<(&'static str, ChordFormula)> {
+        CATALOG.to_vec()
+    }
+
+    /// Looks up a built-in chord formula by its symbol suffix (see [`catalog`](Self::catalog)).
+    ///
+    /// Matching is exact and case-sensitive, since `"m"` and `"M"` differ in chord
+    /// symbols. Returns `None` for an unknown suffix.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ChordFormula;
+    ///
+    /// assert_eq!(ChordFormula::from_symbol("maj7"), Some(ChordFormula::major_seventh()));
+    /// assert_eq!(ChordFormula::from_symbol(""), Some(ChordFormula::major_triad()));
+    /// assert_eq!(ChordFormula::from_symbol("maj8"), None);
+    /// ```
+    pub fn from_symbol(symbol: &str) -> Option<ChordFormula> {
+        CATALOG
+            .iter()
+            .find(|&&(suffix, _)| suffix == symbol)
+            .map(|&(_, formula)| formula)
+    }
+
+    /// Returns the canonical symbol suffix of a built-in chord formula.
+    ///
+    /// When several suffixes name the same formula, the first one in the
+    /// catalog is returned. Returns `None` for any other formula.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ChordFormula;
+    ///
+    /// assert_eq!(ChordFormula::half_diminished_seventh().symbol(), Some("m7b5"));
+    /// assert_eq!(ChordFormula::empty().symbol(), None);
+    /// ```
+    pub fn symbol(&self) -> Option<&'static str> {
+        CATALOG
+            .iter()
+            .find(|(_, formula)| formula == self)
+            .map(|&(suffix, _)| suffix)
+    }
+
+    /// Finds every root that makes an unordered set of pitch classes a built-in chord.
+    ///
+    /// Each distinct pitch class is tried as the root. Matches are ranked by how
+    /// closely the chord follows a stack of thirds, so Am7 comes before C6 for
+    /// the same notes; ties keep the input order. Returns an empty list when no
+    /// root yields a known chord.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{ChordFormula, A, C, E, G};
+    ///
+    /// // The bass does not have to be the root
+    /// assert_eq!(ChordFormula::find_root(&[E, G, C]), vec![(C, ChordFormula::major_triad())]);
+    ///
+    /// let ambiguous = ChordFormula::find_root(&[C, E, G, A]);
+    /// assert_eq!(ambiguous[0], (A, ChordFormula::minor_seventh()));
+    /// assert_eq!(ambiguous[1], (C, ChordFormula::sixth()));
+    /// ```
+    pub fn find_root(notes: &[PitchClass]) -> Vec<(PitchClass, ChordFormula)> {
+        let mask_of = |pitch_classes: &[PitchClass]| {
+            pitch_classes
+                .iter()
+                .fold(0u16, |mask, pitch_class| mask | (1 << pitch_class.value()))
+        };
+        let target = mask_of(notes);
+
+        let mut candidates: Vec<(PitchClass, ChordFormula)> = Vec::new();
+        for &root in notes {
+            for &(_, chord) in CATALOG.iter() {
+                let candidate = (root, chord);
+                if mask_of(&chord.pitch_classes(root)) == target && !candidates.contains(&candidate)
+                {
+                    candidates.push(candidate);
+                }
+            }
+        }
+
+        candidates.sort_by_key(|(_, chord)| chord.stacking_gaps());
+        candidates
+    }
 
-        let shift = (degree - 1) * 2;
-        let actual_value = (self.0 >> shift) & 0b11;
+    /// Counts how far the chord departs from a stack of thirds: every even
+    /// degree (2, 4, 6) and every third missing below the highest degree.
+    fn stacking_gaps(&self) -> usize {
+        let degrees = self.degrees();
+        let highest = degrees.last().map_or(0, |&(degree, _)| degree);
 
-        actual_value == expected
+        let even = degrees.iter().filter(|(degree, _)| degree % 2 == 0).count();
+        let missing = (1..=highest)
+            .step_by(2)
+            .filter(|&degree| !self.has_any_degree(degree))
+            .count();
+        even + missing
     }
 
-    /// Checks if any form of a degree (natural, flat, or sharp) is in the chord.
+    /// Returns the distinct pitch classes of the chord built on `root`, in degree order.
     ///
     /// # Examples
     /// ```
-    /// use musik_std::ChordFormula;
+    /// use musik_std::{ChordFormula, B_FLAT, C, D, E, F, G};
     ///
-    /// let minor_triad = ChordFormula::minor_triad();
-    /// assert!(minor_triad.has_any_degree(3)); // Has flat 3rd
-    /// assert!(!minor_triad.has_any_degree(7)); // No 7th
+    /// assert_eq!(ChordFormula::major_triad().pitch_classes(C), vec![C, E, G]);
+    /// assert_eq!(ChordFormula::major_triad().pitch_classes(B_FLAT), vec![B_FLAT, D, F]);
     /// ```
-    pub const fn has_any_degree(&self, degree: u8) -> bool {
-        if degree == 0 || degree > 15 {
-            return false;
+    pub fn pitch_classes(&self, root: PitchClass) -> Vec<PitchClass> {
+        let mut result: Vec<PitchClass> = Vec::new();
+        for offset in self.semitone_offsets() {
+            let pitch_class = PitchClass::new(root.value() + offset);
+            if !result.contains(&pitch_class) {
+                result.push(pitch_class);
+            }
         }
-
-        let shift = (degree - 1) * 2;
-        let value = (self.0 >> shift) & 0b11;
-
-        value != 0
+        result
     }
 
-    /// Gets the alteration of a degree if it exists in the chord.
+    /// Returns every pair of the chord's pitch classes on `root` that lie a tritone apart.
     ///
-    /// Returns `None` if the degree is not in the chord, or `Some(Option<DegreeAlteration>)`
-    /// where the inner `Option` is `None` for natural and `Some` for altered degrees.
+    /// Pairs follow degree order, so a dominant 7th reports its 3rd and ♭7th
+    /// in that order, ready for [`resolve_tritone`](crate::resolve_tritone).
     ///
     /// # Examples
     /// ```
-    /// use musik_std::{ChordFormula, DegreeAlteration};
+    /// use musik_std::{ChordFormula, B, F, G};
     ///
-    /// let minor_triad = ChordFormula::minor_triad();
-    /// assert_eq!(minor_triad.get_degree_alteration(1), Some(DegreeAlteration::None)); // Natural root
-    /// assert_eq!(minor_triad.get_degree_alteration(3), Some(DegreeAlteration::Flat)); // Flat 3rd
-    /// assert_eq!(minor_triad.get_degree_alteration(7), None); // No 7th
+    /// assert_eq!(ChordFormula::dominant_seventh().tritones(G), vec![(B, F)]);
+    /// assert!(ChordFormula::major_seventh().tritones(G).is_empty());
     /// ```
-    pub const fn get_degree_alteration(&self, degree: u8) -> Option<DegreeAlteration> {
-        if degree == 0 || degree > 15 {
-            return None;
-        }
-
-        let shift = (degree - 1) * 2;
-        let value = (self.0 >> shift) & 0b11;
-
-        match value {
-            0 => None,                                                         // Not in chord
-            v if v >= 1 && v <= 3 => Some(DegreeAlteration::from_u8(v as u8)), // Natural, Flat, or Sharp
-            _ => None, // Should never happen
+    pub fn tritones(&self, root: PitchClass) -> Vec<(PitchClass, PitchClass)> {
+        let pitch_classes = self.pitch_classes(root);
+        let mut pairs = Vec::new();
+        for (index, &low) in pitch_classes.iter().enumerate() {
+            for &high in &pitch_classes[index + 1..] {
+                if PitchClass::new(low.value() + TRITONE) == high {
+                    pairs.push((low, high));
+                }
+            }
         }
+        pairs
     }
 
-    /// Returns a list of all degrees present in the chord with their alterations.
+    /// Returns the chord's pitch classes on each of the twelve chromatic roots, starting from C.
     ///
     /// # Examples
     /// ```
-    /// use musik_std::{ChordFormula, DegreeAlteration};
-    ///
-    /// let minor_seventh = ChordFormula::minor_seventh();
-    /// let degrees = minor_seventh.degrees();
+    /// use musik_std::{ChordFormula, B, C, D, E, G};
     ///
-    /// assert_eq!(degrees.len(), 4);
-    /// assert!(degrees.contains(&(1, DegreeAlteration::None)));
-    /// assert!(degrees.contains(&(3, DegreeAlteration::Flat)));
-    /// assert!(degrees.contains(&(5, DegreeAlteration::None)));
-    /// assert!(degrees.contains(&(7, DegreeAlteration::Flat)));
+    /// let every_key = ChordFormula::major_triad().all_roots();
+    /// assert_eq!(every_key.len(), 12);
+    /// assert_eq!(every_key[0], (C, vec![C, E, G]));
+    /// assert_eq!(every_key[7], (G, vec![G, B, D]));
     /// ```
-    pub fn degrees(&self) -> Vec<(u8, DegreeAlteration)> {
-        let mut result = Vec::new();
-
-        for degree in 1..=15 {
-            if let Some(alteration) = self.get_degree_alteration(degree) {
-                result.push((degree, alteration));
-            }
-        }
-
-        result
+    pub fn all_roots(&self) -> Vec<(PitchClass, Vec<PitchClass>)> {
+        PitchClass::all()
+            .map(|root| (root, self.pitch_classes(root)))
+            .collect()
     }
 
-    /// Combines two chord formulas using bitwise OR.
+    /// Returns the distinct pitch-class intervals of the chord, measured from its lowest one.
+    ///
+    /// Extensions are folded into the octave, so the signature depends only on
+    /// the chord type and can key a recognition cache for chords in any key.
     ///
     /// # Examples
     /// ```
-    /// use musik_std::{ChordFormula, DegreeAlteration};
-    ///
-    /// let triad = ChordFormula::major_triad();
-    /// let seventh = ChordFormula::empty().with_degree(7, DegreeAlteration::None);
-    /// let major_seventh = triad.union(seventh);
+    /// use musik_std::ChordFormula;
     ///
-    /// assert!(major_seventh.has_degree(1, DegreeAlteration::None));
-    /// assert!(major_seventh.has_degree(3, DegreeAlteration::None));
-    /// assert!(major_seventh.has_degree(5, DegreeAlteration::None));
-    /// assert!(major_seventh.has_degree(7, DegreeAlteration::None));
+    /// assert_eq!(ChordFormula::major_triad().interval_signature(), vec![0, 4, 7]);
+    /// assert_eq!(ChordFormula::minor_triad().interval_signature(), vec![0, 3, 7]);
+    /// assert_eq!(ChordFormula::add_ninth().interval_signature(), vec![0, 2, 4, 7]);
     /// ```
-    pub const fn union(self, other: ChordFormula) -> Self {
-        ChordFormula(self.0 | other.0)
+    pub fn interval_signature(&self) -> Vec<u8> {
+        let mut offsets = self.semitone_offsets();
+        offsets.sort_unstable();
+        offsets.dedup();
+
+        let lowest = offsets.first().copied().unwrap_or(0);
+        offsets.iter().map(|offset| offset - lowest).collect()
+    }
+
+    /// Returns the semitone offset from the root (0-11) of every degree in the chord.
+    pub(crate) fn semitone_offsets(&self) -> Vec<u8> {
+        self.formula_degrees()
+            .iter()
+            .filter_map(FormulaDegree::to_semitone_offset)
+            .collect()
     }
 
     // ============================================================================
@@ -626,14 +1624,16 @@ impl ChordFormula {
     // Altered Dominant Chords
     // ============================================================================
 
-    /// Altered dominant: 1 - 3 - ♭7 - ♭9 - ♯9 - ♯11 - ♭13
+    /// Altered dominant: 1 - 3 - ♭7 - ♯9 - ♯11 - ♭13
+    ///
+    /// Each degree holds a single alteration, so the ♭9 of the full altered
+    /// chord cannot sit next to the ♯9 and is left out.
     pub const fn altered_dominant() -> Self {
         ChordFormula(0)
             .with_degree(1, DegreeAlteration::None) // Root
             .with_degree(3, DegreeAlteration::None) // Major 3rd
             .with_degree(7, DegreeAlteration::Flat) // Minor 7th
-            .with_degree(9, DegreeAlteration::Flat) // Flat 9th
-            .with_degree(9, DegreeAlteration::Sharp) // Sharp 9th (both alterations)
+            .with_degree(9, DegreeAlteration::Sharp) // Sharp 9th
             .with_degree(11, DegreeAlteration::Sharp) // Sharp 11th
             .with_degree(13, DegreeAlteration::Flat) // Flat 13th
     }
@@ -696,6 +1696,451 @@ mod tests {
         assert_eq!(empty.degrees().len(), 0);
     }
 
+    #[test]
+    fn test_without_degree() {
+        let dominant = ChordFormula::dominant_seventh();
+        assert_eq!(dominant.without_degree(7), ChordFormula::major_triad());
+        assert_eq!(
+            ChordFormula::minor_seventh().without_degree(7),
+            ChordFormula::minor_triad()
+        );
+
+        // Removing an absent or out-of-range degree is a no-op
+        assert_eq!(dominant.without_degree(9), dominant);
+        assert_eq!(dominant.without_degree(0), dominant);
+        assert_eq!(dominant.without_degree(16), dominant);
+
+        // Inverse of with_degree on an empty slot
+        for degree in 1..=15 {
+            for alteration in [
+                DegreeAlteration::None,
+                DegreeAlteration::Flat,
+                DegreeAlteration::Sharp,
+            ] {
+                let chord = ChordFormula::empty().with_degree(degree, alteration);
+                assert_eq!(chord.without_degree(degree), ChordFormula::empty());
+            }
+        }
+    }
+
+    #[test]
+    fn test_descriptor_round_trip() {
+        for (_, chord) in ChordFormula::catalog() {
+            let descriptor = chord.to_descriptor();
+            assert_eq!(descriptor.bits, chord.bits());
+            assert_eq!(descriptor.degrees.len(), chord.degree_count() as usize);
+            assert_eq!(ChordFormula::from_descriptor(&descriptor), Some(chord));
+        }
+
+        let empty = ChordFormula::empty().to_descriptor();
+        assert!(empty.degrees.is_empty());
+        assert_eq!(
+            ChordFormula::from_descriptor(&empty),
+            Some(ChordFormula::empty())
+        );
+    }
+
+    #[test]
+    fn test_from_descriptor_rejects_invalid() {
+        let major = ChordFormula::major_triad().to_descriptor();
+
+        // Bits that disagree with the degrees
+        let mismatched = ChordDescriptor {
+            bits: major.bits + 1,
+            ..major.clone()
+        };
+        assert_eq!(ChordFormula::from_descriptor(&mismatched), None);
+
+        // Degrees and alterations out of range
+        for degrees in [vec![(0, 0)], vec![(16, 0)], vec![(3, -2)]] {
+            let descriptor = ChordDescriptor { degrees, bits: 0 };
+            assert_eq!(ChordFormula::from_descriptor(&descriptor), None);
+        }
+    }
+
+    #[test]
+    fn test_fluent_builders() {
+        let major_triad = ChordFormula::major_triad();
+        let minor_triad = ChordFormula::minor_triad();
+
+        assert_eq!(major_triad.with_seventh(), ChordFormula::major_seventh());
+        assert_eq!(
+            major_triad.with_seventh().with_ninth(),
+            ChordFormula::major_ninth()
+        );
+        assert_eq!(
+            minor_triad
+                .with_degree(7, DegreeAlteration::Flat)
+                .with_ninth(),
+            ChordFormula::minor_ninth()
+        );
+        assert_eq!(minor_triad.flat_five(), ChordFormula::diminished_triad());
+        assert_eq!(major_triad.sharp_five(), ChordFormula::augmented_triad());
+        assert_eq!(
+            major_triad.sharp_five().with_seventh(),
+            ChordFormula::augmented_major_seventh()
+        );
+        assert_eq!(
+            minor_triad
+                .flat_five()
+                .with_degree(7, DegreeAlteration::Flat),
+            ChordFormula::half_diminished_seventh()
+        );
+
+        // Suspensions replace the 3rd, whatever its quality
+        assert_eq!(major_triad.sus(4), ChordFormula::sus4());
+        assert_eq!(minor_triad.sus(2), ChordFormula::sus2());
+
+        // Order of independent builders does not matter
+        assert_eq!(
+            major_triad.with_ninth().with_seventh(),
+            major_triad.with_seventh().with_ninth()
+        );
+    }
+
+    #[test]
+    fn test_omitted_degrees() {
+        let dominant = ChordFormula::dominant_seventh();
+        assert_eq!(
+            dominant.shell_voicing().degrees(),
+            vec![(3, DegreeAlteration::None), (7, DegreeAlteration::Flat)]
+        );
+        assert_eq!(
+            ChordFormula::minor_seventh().shell_voicing().degrees(),
+            vec![(3, DegreeAlteration::Flat), (7, DegreeAlteration::Flat)]
+        );
+
+        // Shells only keep what the chord has
+        assert_eq!(
+            ChordFormula::major_triad().shell_voicing().degrees(),
+            vec![(3, DegreeAlteration::None)]
+        );
+        assert!(ChordFormula::sus4().shell_voicing().is_empty());
+
+        let rootless = ChordFormula::major_triad().without_root();
+        assert_eq!(
+            rootless.degrees(),
+            vec![(3, DegreeAlteration::None), (5, DegreeAlteration::None)]
+        );
+
+        // The shell is what remains without the root and fifth
+        assert_eq!(
+            dominant.without_root().without_fifth(),
+            dominant.shell_voicing()
+        );
+        assert_eq!(
+            ChordFormula::half_diminished_seventh().without_fifth(),
+            ChordFormula::half_diminished_seventh().without_degree(5)
+        );
+        assert!(!ChordFormula::augmented_triad()
+            .without_fifth()
+            .has_any_degree(5));
+    }
+
+    #[test]
+    fn test_classify_note() {
+        use crate::pitch_class::{A, A_FLAT, B, B_FLAT, C, C_SHARP, D, D_SHARP, E, F, F_SHARP, G};
+
+        let major_seventh = ChordFormula::major_seventh();
+        for chord_tone in [C, E, G, B] {
+            assert_eq!(
+                major_seventh.classify_note(C, chord_tone),
+                NoteRole::ChordTone
+            );
+        }
+        assert_eq!(major_seventh.classify_note(C, D), NoteRole::Tension); // 9
+        assert_eq!(major_seventh.classify_note(C, F_SHARP), NoteRole::Tension); // ♯11
+        assert_eq!(major_seventh.classify_note(C, A), NoteRole::Tension); // 13
+        assert_eq!(major_seventh.classify_note(C, F), NoteRole::Avoid); // Above E
+        assert_eq!(major_seventh.classify_note(C, C_SHARP), NoteRole::Avoid); // Above C
+
+        // Dominant chords accept ♭9 and ♭13, but the 11th still clashes with the 3rd
+        let dominant = ChordFormula::dominant_seventh();
+        assert_eq!(dominant.classify_note(C, B_FLAT), NoteRole::ChordTone);
+        assert_eq!(dominant.classify_note(C, C_SHARP), NoteRole::Tension);
+        assert_eq!(dominant.classify_note(C, D_SHARP), NoteRole::Tension);
+        assert_eq!(dominant.classify_note(C, A_FLAT), NoteRole::Tension);
+        assert_eq!(dominant.classify_note(C, F), NoteRole::Avoid);
+
+        // Minor seventh: ♭13 sits a semitone above the 5th
+        let minor_seventh = ChordFormula::minor_seventh();
+        assert_eq!(minor_seventh.classify_note(C, F), NoteRole::Tension); // 11
+        assert_eq!(minor_seventh.classify_note(C, A_FLAT), NoteRole::Avoid);
+
+        // Classification is relative to the root
+        assert_eq!(major_seventh.classify_note(G, B), NoteRole::ChordTone);
+        assert_eq!(major_seventh.classify_note(G, C), NoteRole::Avoid);
+        assert_eq!(major_seventh.classify_note(G, A), NoteRole::Tension);
+
+        // Without chord tones nothing can clash
+        assert_eq!(ChordFormula::empty().classify_note(C, C), NoteRole::Tension);
+    }
+
+    #[test]
+    fn test_dissonance_score() {
+        let score = |chord: ChordFormula| chord.dissonance_score();
+
+        assert!((score(ChordFormula::major_triad()) - 0.2).abs() < 1e-12);
+        assert_eq!(
+            score(ChordFormula::major_triad()),
+            score(ChordFormula::minor_triad())
+        );
+        assert!(
+            score(ChordFormula::major_triad()) < score(ChordFormula::dominant_seventh_flat_ninth())
+        );
+        assert!(
+            score(ChordFormula::dominant_seventh())
+                < score(ChordFormula::dominant_seventh_flat_ninth())
+        );
+        assert!(score(ChordFormula::dominant_seventh()) < score(ChordFormula::altered_dominant()));
+
+        // A major 2nd (0.5) is harsher than a third (0.1)
+        assert!(score(ChordFormula::sus2()) > score(ChordFormula::major_triad()));
+
+        // The 9th of add9 adds a major 2nd against the root and the 3rd, and a fifth against the 5th
+        assert!((score(ChordFormula::add_ninth()) - 1.2).abs() < 1e-12);
+
+        // Doubling a pitch class an octave up adds nothing
+        let doubled = ChordFormula::major_triad().with_degree(8, DegreeAlteration::None);
+        assert_eq!(score(doubled), score(ChordFormula::major_triad()));
+
+        assert_eq!(score(ChordFormula::empty()), 0.0);
+        assert_eq!(
+            score(ChordFormula::empty().with_degree(1, DegreeAlteration::None)),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_interval_signature() {
+        let major = ChordFormula::major_triad();
+        let minor = ChordFormula::minor_triad();
+        assert_eq!(major.interval_signature(), vec![0, 4, 7]);
+        assert_ne!(major.interval_signature(), minor.interval_signature());
+
+        // Every transposition of the chord shares the signature of its intervals
+        for (root, pitch_classes) in major.all_roots() {
+            let intervals: Vec<u8> = pitch_classes
+                .iter()
+                .map(|pc| (pc.value() + SEMITONES_IN_OCTAVE - root.value()) % SEMITONES_IN_OCTAVE)
+                .collect();
+            assert_eq!(intervals, major.interval_signature());
+        }
+
+        // Extensions fold into the octave
+        assert_eq!(
+            ChordFormula::dominant_ninth().interval_signature(),
+            vec![0, 2, 4, 7, 10]
+        );
+
+        // Without a root, intervals are measured from the lowest remaining note
+        assert_eq!(major.without_degree(1).interval_signature(), vec![0, 3]);
+        assert!(ChordFormula::empty().interval_signature().is_empty());
+    }
+
+    #[test]
+    fn test_find_root() {
+        use crate::pitch_class::{A, B, C, D, D_SHARP, E, F, F_SHARP, G};
+
+        // Inversions find the same root
+        for notes in [[C, E, G], [E, G, C], [G, C, E]] {
+            assert_eq!(
+                ChordFormula::find_root(&notes),
+                vec![(C, ChordFormula::major_triad())]
+            );
+        }
+
+        // Duplicates do not matter
+        assert_eq!(
+            ChordFormula::find_root(&[D, F, A, D]),
+            vec![(D, ChordFormula::minor_triad())]
+        );
+
+        // Tertian readings rank first
+        let ambiguous = ChordFormula::find_root(&[C, E, G, A]);
+        assert_eq!(
+            ambiguous,
+            vec![
+                (A, ChordFormula::minor_seventh()),
+                (C, ChordFormula::sixth())
+            ]
+        );
+
+        // The suspended chords share notes; both readings are reported
+        let suspended = ChordFormula::find_root(&[C, D, G]);
+        assert!(suspended.contains(&(C, ChordFormula::sus2())));
+        assert!(suspended.contains(&(G, ChordFormula::sus4())));
+
+        // Symmetric chords report every root
+        assert_eq!(ChordFormula::find_root(&[C, D_SHARP, F_SHARP, A]).len(), 4);
+
+        assert!(ChordFormula::find_root(&[C, F_SHARP, B]).is_empty());
+        assert!(ChordFormula::find_root(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_tritones() {
+        use crate::pitch_class::{A, B, C, D_SHARP, E, F, F_SHARP, G};
+
+        assert_eq!(ChordFormula::dominant_seventh().tritones(G), vec![(B, F)]);
+        assert_eq!(
+            ChordFormula::half_diminished_seventh().tritones(B),
+            vec![(B, F)]
+        );
+        assert_eq!(ChordFormula::diminished_triad().tritones(B), vec![(B, F)]);
+
+        // A fully diminished 7th stacks two tritones
+        assert_eq!(
+            ChordFormula::fully_diminished_seventh().tritones(C),
+            vec![(C, F_SHARP), (D_SHARP, A)]
+        );
+
+        // Stable chords have none
+        assert!(ChordFormula::major_triad().tritones(C).is_empty());
+        assert!(ChordFormula::minor_seventh().tritones(E).is_empty());
+        assert!(ChordFormula::empty().tritones(C).is_empty());
+    }
+
+    #[test]
+    fn test_all_roots() {
+        use crate::pitch_class::{A, B, C, D, E, F, F_SHARP, G};
+
+        let major = ChordFormula::major_triad();
+        let every_key = major.all_roots();
+        assert_eq!(every_key.len(), 12);
+        assert_eq!(every_key[0], (C, vec![C, E, G]));
+        assert_eq!(every_key[7], (G, vec![G, B, D]));
+
+        // Each entry is the root-position triad transposed to its root
+        for (index, (root, pitch_classes)) in every_key.iter().enumerate() {
+            assert_eq!(root.value() as usize, index);
+            let expected: Vec<PitchClass> = [0, 4, 7]
+                .iter()
+                .map(|&offset| PitchClass::new(root.value() + offset))
+                .collect();
+            assert_eq!(pitch_classes, &expected);
+        }
+
+        let dominant = ChordFormula::dominant_seventh().all_roots();
+        assert_eq!(dominant[2], (D, vec![D, F_SHARP, A, C]));
+        assert!(dominant.iter().all(|(_, notes)| notes.len() == 4));
+        assert_eq!(dominant[5].1[0], F);
+
+        assert!(ChordFormula::empty()
+            .all_roots()
+            .iter()
+            .all(|(_, notes)| notes.is_empty()));
+    }
+
+    #[test]
+    fn test_pitch_classes() {
+        use crate::pitch_class::{A, B_FLAT, C, D, E, E_FLAT, F, F_SHARP, G};
+
+        assert_eq!(ChordFormula::major_triad().pitch_classes(C), vec![C, E, G]);
+        assert_eq!(
+            ChordFormula::minor_seventh().pitch_classes(C),
+            vec![C, E_FLAT, G, B_FLAT]
+        );
+        assert_eq!(
+            ChordFormula::dominant_seventh().pitch_classes(D),
+            vec![D, F_SHARP, A, C]
+        );
+
+        // Extensions fold onto single pitch classes
+        assert_eq!(
+            ChordFormula::major_ninth().pitch_classes(C).len(),
+            ChordFormula::major_ninth().degree_count() as usize
+        );
+        assert!(ChordFormula::major_ninth().pitch_classes(F).contains(&G));
+
+        // An octave doubling does not repeat the root
+        let doubled = ChordFormula::major_triad().with_degree(8, DegreeAlteration::None);
+        assert_eq!(doubled.pitch_classes(C), vec![C, E, G]);
+
+        assert!(ChordFormula::empty().pitch_classes(C).is_empty());
+    }
+
+    #[test]
+    fn test_catalog() {
+        let catalog = ChordFormula::catalog();
+        assert_eq!(catalog.len(), 37);
+        assert!(catalog.contains(&("", ChordFormula::major_triad())));
+        assert!(catalog.contains(&("m7", ChordFormula::minor_seventh())));
+        assert!(catalog.contains(&("7", ChordFormula::dominant_seventh())));
+        assert!(catalog.contains(&("6/9", ChordFormula::six_nine())));
+
+        // Every suffix is unique and round-trips through from_symbol()
+        for (index, &(suffix, formula)) in catalog.iter().enumerate() {
+            assert_eq!(ChordFormula::from_symbol(suffix), Some(formula));
+            assert!(catalog[index + 1..]
+                .iter()
+                .all(|&(other, _)| other != suffix));
+        }
+
+        // symbol() returns a suffix that maps back to the same formula
+        for &(_, formula) in &catalog {
+            let suffix = formula.symbol().unwrap();
+            assert_eq!(ChordFormula::from_symbol(suffix), Some(formula));
+        }
+    }
+
+    #[test]
+    fn test_from_symbol() {
+        assert_eq!(
+            ChordFormula::from_symbol("m"),
+            Some(ChordFormula::minor_triad())
+        );
+        assert_eq!(
+            ChordFormula::from_symbol("m7b5"),
+            Some(ChordFormula::half_diminished_seventh())
+        );
+        assert_eq!(
+            ChordFormula::from_symbol("13#11"),
+            Some(ChordFormula::dominant_thirteenth_sharp_eleventh())
+        );
+
+        // Exact, case-sensitive matching
+        assert_eq!(ChordFormula::from_symbol("M"), None);
+        assert_eq!(ChordFormula::from_symbol("MAJ7"), None);
+        assert_eq!(ChordFormula::from_symbol(" m7"), None);
+        assert_eq!(ChordFormula::from_symbol("Cm7"), None);
+    }
+
+    #[test]
+    fn test_symbol() {
+        assert_eq!(ChordFormula::major_triad().symbol(), Some(""));
+        assert_eq!(ChordFormula::minor_seventh().symbol(), Some("m7"));
+
+        // Augmented 7th and 7♯5 are the same formula
+        assert_eq!(
+            ChordFormula::dominant_seventh_sharp_fifth().symbol(),
+            Some("aug7")
+        );
+
+        assert_eq!(ChordFormula::empty().symbol(), None);
+        assert_eq!(ChordFormula::major_triad().without_degree(3).symbol(), None);
+    }
+
+    #[test]
+    fn test_degree_count() {
+        assert_eq!(ChordFormula::empty().degree_count(), 0);
+        assert_eq!(ChordFormula::major_triad().degree_count(), 3);
+        assert_eq!(ChordFormula::dominant_seventh().degree_count(), 4);
+        assert_eq!(ChordFormula::major_thirteenth().degree_count(), 7);
+
+        for chord in [
+            ChordFormula::sus4(),
+            ChordFormula::minor_ninth(),
+            ChordFormula::dominant_thirteenth_sharp_eleventh(),
+            ChordFormula::empty().with_degree(15, DegreeAlteration::None),
+        ] {
+            assert_eq!(chord.degree_count() as usize, chord.degrees().len());
+        }
+
+        // The reserved bits 30-31 are not a degree
+        assert_eq!(ChordFormula::new(0b11 << 30).degree_count(), 0);
+    }
+
     #[test]
     fn test_major_triad() {
         let major = ChordFormula::major_triad();
@@ -870,6 +2315,222 @@ mod tests {
         assert_eq!((bits >> 8) & 0b11, 1); // 5th = 1 (natural)
     }
 
+    #[test]
+    fn test_voice_near() {
+        use crate::pitch_class::{C, D, G};
+
+        let c_major = ChordFormula::major_triad();
+        let middle_c = Note::new(60);
+
+        let voicing = c_major.voice_near(C, middle_c);
+        assert_eq!(voicing, vec![Note::new(55), Note::new(60), Note::new(64)]);
+        for note in &voicing {
+            assert!(note.semitone().abs_diff(middle_c.semitone()) <= 12);
+        }
+
+        // Shifting the target by an octave shifts the whole voicing
+        let higher: Vec<u8> = c_major
+            .voice_near(C, Note::new(72))
+            .iter()
+            .map(|note| note.semitone() - 12)
+            .collect();
+        let lower: Vec<u8> = voicing.iter().map(|note| note.semitone()).collect();
+        assert_eq!(higher, lower);
+
+        // Spread stays within an octave even for extended chords
+        let g13 = ChordFormula::dominant_thirteenth().voice_near(G, Note::new(64));
+        assert_eq!(g13.len(), 7);
+        let spread = g13.last().unwrap().semitone() - g13.first().unwrap().semitone();
+        assert!(spread < 12);
+
+        // Pitch classes are preserved
+        let d_minor = ChordFormula::minor_triad().voice_near(D, Note::new(60));
+        let pitch_classes: Vec<u8> = d_minor.iter().map(|n| n.pitch_class().value()).collect();
+        assert_eq!(pitch_classes, vec![9, 2, 5]); // A3, D4, F4
+
+        // Voicings near the edges of the note range stay valid
+        let low = c_major.voice_near(C, Note::new(0));
+        assert_eq!(low, vec![Note::new(0), Note::new(4), Note::new(7)]);
+
+        assert!(ChordFormula::empty().voice_near(C, middle_c).is_empty());
+    }
+
+    #[test]
+    fn test_minimal_motion_to() {
+        use crate::pitch_class::{C, D, F, G};
+
+        let major = ChordFormula::major_triad();
+
+        // C major to F major: C is held, E and G move up by step
+        let motion = major.minimal_motion_to(C, &major, F);
+        assert_eq!(
+            motion,
+            vec![
+                (Note::new(55), Note::new(57)),
+                (Note::new(60), Note::new(60)),
+                (Note::new(64), Note::new(65)),
+            ]
+        );
+        let total: u8 = motion
+            .iter()
+            .map(|&(from, to)| from.semitone().abs_diff(to.semitone()))
+            .sum();
+        assert_eq!(total, 3);
+
+        // G7 to C: the leading tone resolves up, the 7th down
+        let dominant = ChordFormula::dominant_seventh().minimal_motion_to(G, &major, C);
+        assert_eq!(dominant.len(), 4);
+        assert!(dominant.contains(&(Note::new(59), Note::new(60)))); // B3 to C4
+        assert!(dominant.contains(&(Note::new(65), Note::new(64)))); // F4 to E4
+        assert!(dominant.contains(&(Note::new(55), Note::new(55)))); // G3 held
+
+        // Every voice moves by at most a tritone
+        for (root, other) in [(D, ChordFormula::minor_seventh()), (F, major)] {
+            for (from, to) in major.minimal_motion_to(C, &other, root) {
+                assert!(from.semitone().abs_diff(to.semitone()) <= 6);
+            }
+        }
+
+        // Same chord: nothing moves
+        for (from, to) in major.minimal_motion_to(C, &major, C) {
+            assert_eq!(from, to);
+        }
+
+        assert!(major
+            .minimal_motion_to(C, &ChordFormula::empty(), F)
+            .is_empty());
+        assert!(ChordFormula::empty()
+            .minimal_motion_to(C, &major, F)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_frequencies() {
+        let c4 = Note::new(60);
+
+        let triad = ChordFormula::major_triad().frequencies(c4);
+        assert_eq!(
+            triad,
+            vec![
+                c4.frequency(),
+                Note::new(64).frequency(),
+                Note::new(67).frequency()
+            ]
+        );
+        assert!(triad.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!((triad[2] / triad[0] - 1.5).abs() < 0.01);
+
+        // Extensions sound in the upper octave
+        let ninth = ChordFormula::dominant_ninth().frequencies(c4);
+        assert_eq!(ninth.len(), 5);
+        assert_eq!(ninth[4], Note::new(74).frequency()); // D5
+        assert!(ninth.windows(2).all(|pair| pair[0] < pair[1]));
+
+        // Notes past the top of the range are dropped
+        assert_eq!(
+            ChordFormula::major_triad().frequencies(Note::new(250)),
+            vec![Note::new(250).frequency(), Note::new(254).frequency()]
+        );
+        assert!(ChordFormula::empty().frequencies(c4).is_empty());
+    }
+
+    #[test]
+    fn test_all_inversions() {
+        let c4 = Note::new(60);
+
+        let triad = ChordFormula::major_triad().all_inversions(c4);
+        assert_eq!(triad.len(), 3);
+
+        let seventh = ChordFormula::dominant_seventh().all_inversions(c4);
+        assert_eq!(seventh.len(), 4);
+        assert_eq!(
+            seventh[3],
+            vec![Note::new(70), Note::new(72), Note::new(76), Note::new(79)]
+        );
+
+        // Each voicing rotates the previous one, raising the bottom note an octave
+        for voicings in [&triad, &seventh] {
+            for pair in voicings.windows(2) {
+                assert_eq!(pair[1][..pair[1].len() - 1], pair[0][1..]);
+                assert_eq!(
+                    pair[1].last().unwrap().semitone(),
+                    pair[0][0].semitone() + 12
+                );
+            }
+        }
+
+        // Inversion numbers follow the bass note
+        use crate::pitch_class::C;
+        for (inversion, voicing) in seventh.iter().enumerate() {
+            assert_eq!(
+                ChordFormula::dominant_seventh().detect_inversion(C, voicing),
+                Some(inversion as u8)
+            );
+        }
+
+        // Extensions stay in the upper octave in root position
+        let ninth = ChordFormula::major_ninth().all_inversions(c4);
+        assert_eq!(ninth.len(), 5);
+        assert_eq!(*ninth[0].last().unwrap(), Note::new(74));
+
+        // Inversions past the top of the note range are left out
+        assert_eq!(
+            ChordFormula::major_triad()
+                .all_inversions(Note::new(240))
+                .len(),
+            2
+        );
+        assert!(ChordFormula::major_triad()
+            .all_inversions(Note::new(250))
+            .is_empty());
+
+        // Near the top of the range only the voicings that fit are kept
+        let near_top = Note::new(245);
+        let fitting = ChordFormula::major_triad().all_inversions(near_top);
+        assert_eq!(
+            fitting,
+            vec![vec![Note::new(245), Note::new(249), Note::new(252)]]
+        );
+        assert!(ChordFormula::major_ninth()
+            .all_inversions(near_top)
+            .is_empty());
+        assert!(ChordFormula::major_triad()
+            .all_inversions(Note::new(255))
+            .is_empty());
+        for voicing in ChordFormula::dominant_seventh().all_inversions(Note::new(240)) {
+            assert_eq!(voicing.len(), 4);
+        }
+
+        assert!(ChordFormula::empty().all_inversions(c4).is_empty());
+    }
+
+    #[test]
+    fn test_detect_inversion() {
+        use crate::note::notes;
+        use crate::pitch_class::{C, D, G};
+
+        let c_major = ChordFormula::major_triad();
+        assert_eq!(c_major.detect_inversion(C, &notes(&[60, 64, 67])), Some(0));
+        assert_eq!(c_major.detect_inversion(C, &notes(&[64, 67, 72])), Some(1));
+        assert_eq!(c_major.detect_inversion(C, &notes(&[55, 60, 64])), Some(2));
+
+        // Only the lowest note matters, whatever the order and spacing
+        assert_eq!(c_major.detect_inversion(C, &notes(&[72, 79, 52])), Some(1));
+        assert_eq!(
+            c_major.detect_inversion(C, &notes(&[36, 64, 67, 72])),
+            Some(0)
+        );
+
+        // Third inversion of a seventh chord
+        let g7 = ChordFormula::dominant_seventh();
+        assert_eq!(g7.detect_inversion(G, &notes(&[53, 59, 62, 67])), Some(3));
+
+        // Bass outside the chord, or nothing to analyze
+        assert_eq!(c_major.detect_inversion(C, &notes(&[62, 64, 67])), None);
+        assert_eq!(c_major.detect_inversion(D, &notes(&[60, 64, 67])), None);
+        assert_eq!(c_major.detect_inversion(C, &[]), None);
+    }
+
     #[test]
     fn test_const_functions() {
         const MAJOR: ChordFormula = ChordFormula::major_triad();
@@ -883,4 +2544,276 @@ mod tests {
         assert!(MINOR.has_degree(3, DegreeAlteration::Flat));
         assert!(MINOR.has_degree(5, DegreeAlteration::None));
     }
+
+    #[test]
+    fn test_formula_degrees() {
+        let minor_seventh = ChordFormula::minor_seventh().formula_degrees();
+        assert!(minor_seventh.contains(&FormulaDegree::flat(3)));
+        assert!(minor_seventh.contains(&FormulaDegree::flat(7)));
+        assert_eq!(minor_seventh.len(), 4);
+
+        let altered = ChordFormula::dominant_seventh_sharp_ninth().formula_degrees();
+        assert_eq!(
+            altered,
+            vec![
+                FormulaDegree::natural(1),
+                FormulaDegree::natural(3),
+                FormulaDegree::natural(5),
+                FormulaDegree::flat(7),
+                FormulaDegree::sharp(9),
+            ]
+        );
+
+        // Matches degrees() slot for slot
+        let thirteenth = ChordFormula::dominant_thirteenth();
+        assert_eq!(
+            thirteenth.formula_degrees().len(),
+            thirteenth.degrees().len()
+        );
+        for (formula_degree, (degree, _)) in thirteenth
+            .formula_degrees()
+            .into_iter()
+            .zip(thirteenth.degrees())
+        {
+            assert_eq!(formula_degree.base_degree(), degree);
+        }
+
+        assert!(ChordFormula::empty().formula_degrees().is_empty());
+    }
+
+    #[test]
+    fn test_from_formula_degrees() {
+        let formula = ChordFormula::from_formula_degrees(&[
+            FormulaDegree::natural(1),
+            FormulaDegree::natural(3),
+            FormulaDegree::natural(5),
+            FormulaDegree::flat(7),
+            FormulaDegree::flat(9),
+        ]);
+        assert_eq!(formula, ChordFormula::dominant_seventh_flat_ninth());
+
+        // Extended degrees up to 15 are kept, out-of-range degrees ignored
+        let extended = ChordFormula::from_formula_degrees(&[
+            FormulaDegree::natural(1),
+            FormulaDegree::sharp(11),
+            FormulaDegree::natural(13),
+            FormulaDegree::natural(15),
+            FormulaDegree::natural(0),
+            FormulaDegree::natural(16),
+        ]);
+        assert_eq!(
+            extended.formula_degrees(),
+            vec![
+                FormulaDegree::natural(1),
+                FormulaDegree::sharp(11),
+                FormulaDegree::natural(13),
+                FormulaDegree::natural(15),
+            ]
+        );
+
+        // Round-trips through formula_degrees
+        for chord in [
+            ChordFormula::minor_seventh(),
+            ChordFormula::half_diminished_seventh(),
+            ChordFormula::dominant_thirteenth_sharp_eleventh(),
+            ChordFormula::altered_dominant(),
+        ] {
+            assert_eq!(
+                ChordFormula::from_formula_degrees(&chord.formula_degrees()),
+                chord
+            );
+        }
+
+        assert_eq!(
+            ChordFormula::from_formula_degrees(&[]),
+            ChordFormula::empty()
+        );
+    }
+
+    #[test]
+    fn test_normalize_spelling() {
+        // ♭10 collides with the major 3rd and becomes ♯9
+        let flat_ten = ChordFormula::dominant_seventh().with_degree(10, DegreeAlteration::Flat);
+        assert_eq!(
+            flat_ten.normalize_spelling(),
+            ChordFormula::dominant_seventh_sharp_ninth()
+        );
+
+        // ♭12 and ♯12 collide with the 5th and become ♯11 and ♭13
+        let flat_twelve = ChordFormula::dominant_seventh().with_degree(12, DegreeAlteration::Flat);
+        assert_eq!(
+            flat_twelve.normalize_spelling(),
+            ChordFormula::dominant_seventh_sharp_eleventh()
+        );
+        let sharp_twelve = ChordFormula::dominant_seventh()
+            .with_degree(12, DegreeAlteration::Sharp)
+            .normalize_spelling();
+        assert!(sharp_twelve.has_degree(13, DegreeAlteration::Flat));
+        assert!(!sharp_twelve.has_any_degree(12));
+
+        // Pitch classes are never changed
+        for misspelled in [flat_ten, flat_twelve] {
+            let mut before = misspelled.semitone_offsets();
+            let mut after = misspelled.normalize_spelling().semitone_offsets();
+            before.sort();
+            after.sort();
+            assert_eq!(before, after);
+        }
+
+        // No enharmonic alternative: ♭8 cannot be a ♯7
+        let flat_eight = ChordFormula::major_triad().with_degree(8, DegreeAlteration::Flat);
+        assert_eq!(flat_eight.normalize_spelling(), flat_eight);
+    }
+
+    #[test]
+    fn test_normalize_spelling_keeps_well_spelled_chords() {
+        let chords = [
+            ChordFormula::major_triad(),
+            ChordFormula::half_diminished_seventh(),
+            ChordFormula::dominant_thirteenth_flat_ninth(),
+            ChordFormula::dominant_thirteenth_sharp_eleventh(),
+            ChordFormula::altered_dominant(),
+            ChordFormula::six_nine(),
+        ];
+        for chord in chords {
+            assert_eq!(chord.normalize_spelling(), chord);
+        }
+
+        // The altered dominant spells every tone on its own letter and pitch class
+        let altered = ChordFormula::altered_dominant();
+        let normalized = altered.normalize_spelling();
+        let mut letters: Vec<u8> = normalized
+            .formula_degrees()
+            .iter()
+            .map(|degree| (degree.base_degree() - 1) % 7)
+            .collect();
+        letters.sort();
+        letters.dedup();
+        assert_eq!(letters.len(), normalized.degree_count() as usize);
+
+        let mut offsets = normalized.semitone_offsets();
+        offsets.sort();
+        assert_eq!(offsets, vec![0, 3, 4, 6, 8, 10]);
+        assert!(normalized.has_degree(9, DegreeAlteration::Sharp));
+
+        // A ♯9 misspelled as ♭10 is respelled even among the other tensions
+        let flat_ten = altered
+            .without_degree(9)
+            .with_degree(10, DegreeAlteration::Flat);
+        assert_eq!(flat_ten.normalize_spelling(), altered);
+
+        // A ♭12 written for the ♯11 is still respelled around the other tensions
+        let misspelled = altered
+            .without_degree(11)
+            .with_degree(12, DegreeAlteration::Flat)
+            .with_degree(5, DegreeAlteration::None);
+        let respelled = misspelled.normalize_spelling();
+        assert!(respelled.has_degree(11, DegreeAlteration::Sharp));
+        assert!(!respelled.has_any_degree(12));
+        assert_eq!(respelled.semitone_offsets().len(), 7);
+    }
+
+    #[test]
+    fn test_primary_scale() {
+        use crate::pitch_class::{B, C, D, G};
+
+        // Textbook parent scale for each seventh chord
+        assert_eq!(
+            ChordFormula::major_seventh().primary_scale(C),
+            Some((C, ScaleFormula::major()))
+        );
+        assert_eq!(
+            ChordFormula::dominant_seventh().primary_scale(G),
+            Some((G, ScaleFormula::mixolydian()))
+        );
+        assert_eq!(
+            ChordFormula::minor_seventh().primary_scale(D),
+            Some((D, ScaleFormula::dorian()))
+        );
+        assert_eq!(
+            ChordFormula::half_diminished_seventh().primary_scale(B),
+            Some((B, ScaleFormula::locrian()))
+        );
+        assert_eq!(
+            ChordFormula::altered_dominant().primary_scale(G),
+            Some((G, ScaleFormula::altered()))
+        );
+        assert_eq!(
+            ChordFormula::fully_diminished_seventh().primary_scale(B),
+            Some((B, ScaleFormula::octatonic_wh()))
+        );
+
+        // Extensions keep their parent scale
+        assert_eq!(
+            ChordFormula::dominant_thirteenth().primary_scale(G),
+            Some((G, ScaleFormula::mixolydian()))
+        );
+        assert_eq!(
+            ChordFormula::minor_eleventh().primary_scale(D),
+            Some((D, ScaleFormula::dorian()))
+        );
+
+        // Every chord tone belongs to its primary scale
+        for (chord, _) in PRIMARY_SCALES {
+            let (scale_root, scale) = chord.primary_scale(C).unwrap();
+            for tone in chord.pitch_classes(C) {
+                assert!(scale.contains_pitch_class(scale_root, tone), "{chord}");
+            }
+        }
+
+        assert_eq!(ChordFormula::major_triad().primary_scale(C), None);
+        assert_eq!(ChordFormula::sus4().primary_scale(C), None);
+    }
+
+    #[test]
+    fn test_compatible_scales() {
+        use crate::pitch_class::{B, C, D, E, F, G, G_SHARP};
+
+        // Dominant 7th: Mixolydian on the root, major on the fourth
+        let dominant = ChordFormula::dominant_seventh().compatible_scales(G);
+        assert_eq!(dominant[0], (G, ScaleFormula::mixolydian()));
+        assert!(dominant.contains(&(C, ScaleFormula::major())));
+        assert!(!dominant.contains(&(G, ScaleFormula::major())));
+
+        // Major 7th: Ionian and Lydian on the root
+        let major_seventh = ChordFormula::major_seventh().compatible_scales(C);
+        assert_eq!(
+            &major_seventh[..2],
+            &[(C, ScaleFormula::major()), (C, ScaleFormula::lydian())]
+        );
+        assert!(!major_seventh.contains(&(C, ScaleFormula::mixolydian())));
+
+        // Minor 7th: Dorian, Phrygian, Aeolian and the minor pentatonic on the root
+        let minor_seventh = ChordFormula::minor_seventh().compatible_scales(D);
+        let on_root: Vec<ScaleFormula> = minor_seventh
+            .iter()
+            .filter(|(scale_root, _)| *scale_root == D)
+            .map(|&(_, scale)| scale)
+            .collect();
+        assert_eq!(
+            on_root,
+            vec![
+                ScaleFormula::dorian(),
+                ScaleFormula::phrygian(),
+                ScaleFormula::minor(),
+                ScaleFormula::pentatonic_minor(),
+                ScaleFormula::blues(),
+            ]
+        );
+
+        // Every suggestion contains all chord tones
+        for (scale_root, scale) in ChordFormula::major_triad().compatible_scales(E) {
+            for tone in [E, G_SHARP, B] {
+                assert!(scale.contains_pitch_class(scale_root, tone));
+            }
+        }
+
+        // Nothing fits four consecutive semitones
+        let cluster = ChordFormula::empty()
+            .with_degree(1, DegreeAlteration::None)
+            .with_degree(2, DegreeAlteration::Flat)
+            .with_degree(9, DegreeAlteration::None)
+            .with_degree(3, DegreeAlteration::Flat);
+        assert!(cluster.compatible_scales(F).is_empty());
+    }
 }
@@ -0,0 +1,147 @@
+//! Triad qualities for labeling diatonic harmony.
+//!
+//! This module provides the `ChordQuality` enum describing the four triad
+//! qualities built from stacked thirds.
+
+use crate::chord_formula::ChordFormula;
+use std::fmt;
+
+/// The quality of a triad, determined by its third and fifth.
+///
+/// # Examples
+/// ```
+/// use musik_std::{ChordFormula, ChordQuality};
+///
+/// assert_eq!(ChordQuality::Minor.triad(), ChordFormula::minor_triad());
+/// assert_eq!(ChordQuality::from_intervals(4, 7), Some(ChordQuality::Major));
+/// assert_eq!(format!("{}", ChordQuality::Diminished), "Diminished");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChordQuality {
+    /// Major third and perfect fifth
+    Major,
+    /// Minor third and perfect fifth
+    Minor,
+    /// Minor third and diminished fifth
+    Diminished,
+    /// Major third and augmented fifth
+    Augmented,
+}
+
+impl ChordQuality {
+    /// Get the quality of a triad from its third and fifth, in semitones above the root.
+    ///
+    /// Returns `None` if the intervals do not form one of the four tertian triads.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ChordQuality;
+    ///
+    /// assert_eq!(ChordQuality::from_intervals(3, 7), Some(ChordQuality::Minor));
+    /// assert_eq!(ChordQuality::from_intervals(3, 6), Some(ChordQuality::Diminished));
+    /// assert_eq!(ChordQuality::from_intervals(4, 8), Some(ChordQuality::Augmented));
+    /// assert_eq!(ChordQuality::from_intervals(5, 7), None); // Suspended
+    /// ```
+    pub const fn from_intervals(third: u8, fifth: u8) -> Option<Self> {
+        match (third, fifth) {
+            (4, 7) => Some(ChordQuality::Major),
+            (3, 7) => Some(ChordQuality::Minor),
+            (3, 6) => Some(ChordQuality::Diminished),
+            (4, 8) => Some(ChordQuality::Augmented),
+            _ => None,
+        }
+    }
+
+    /// Get the triad formula for this quality.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{ChordFormula, ChordQuality};
+    ///
+    /// assert_eq!(ChordQuality::Augmented.triad(), ChordFormula::augmented_triad());
+    /// ```
+    pub const fn triad(&self) -> ChordFormula {
+        match self {
+            ChordQuality::Major => ChordFormula::major_triad(),
+            ChordQuality::Minor => ChordFormula::minor_triad(),
+            ChordQuality::Diminished => ChordFormula::diminished_triad(),
+            ChordQuality::Augmented => ChordFormula::augmented_triad(),
+        }
+    }
+
+    /// Get the name of the quality.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ChordQuality;
+    ///
+    /// assert_eq!(ChordQuality::Major.name(), "Major");
+    /// ```
+    pub const fn name(&self) -> &'static str {
+        match self {
+            ChordQuality::Major => "Major",
+            ChordQuality::Minor => "Minor",
+            ChordQuality::Diminished => "Diminished",
+            ChordQuality::Augmented => "Augmented",
+        }
+    }
+}
+
+impl fmt::Display for ChordQuality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_intervals() {
+        assert_eq!(
+            ChordQuality::from_intervals(4, 7),
+            Some(ChordQuality::Major)
+        );
+        assert_eq!(
+            ChordQuality::from_intervals(3, 7),
+            Some(ChordQuality::Minor)
+        );
+        assert_eq!(
+            ChordQuality::from_intervals(3, 6),
+            Some(ChordQuality::Diminished)
+        );
+        assert_eq!(
+            ChordQuality::from_intervals(4, 8),
+            Some(ChordQuality::Augmented)
+        );
+
+        assert_eq!(ChordQuality::from_intervals(2, 7), None);
+        assert_eq!(ChordQuality::from_intervals(4, 6), None);
+        assert_eq!(ChordQuality::from_intervals(0, 0), None);
+    }
+
+    #[test]
+    fn test_triad_matches_intervals() {
+        for quality in [
+            ChordQuality::Major,
+            ChordQuality::Minor,
+            ChordQuality::Diminished,
+            ChordQuality::Augmented,
+        ] {
+            let offsets = quality.triad().semitone_offsets();
+            assert_eq!(offsets.len(), 3);
+            assert_eq!(
+                ChordQuality::from_intervals(offsets[1], offsets[2]),
+                Some(quality)
+            );
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", ChordQuality::Major), "Major");
+        assert_eq!(format!("{}", ChordQuality::Minor), "Minor");
+        assert_eq!(format!("{}", ChordQuality::Augmented), "Augmented");
+    }
+}
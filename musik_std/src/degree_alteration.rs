@@ -3,6 +3,8 @@
 //! This module provides the `DegreeAlteration` enum for representing musical alterations
 //! that modify scale degrees, chord tones, and other musical intervals.
 
+use crate::pitch_class::PitchClass;
+use crate::semitone::{Semitone, SEMITONES_IN_OCTAVE};
 use std::fmt;
 
 /// Represents a musical alteration that modifies a degree.
@@ -76,6 +78,42 @@ impl DegreeAlteration {
         }
     }
 
+    /// Apply the alteration to a pitch class, wrapping around the octave.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{DegreeAlteration, B, C, D_SHARP, E, F, F_SHARP};
+    ///
+    /// assert_eq!(DegreeAlteration::Flat.apply(E), D_SHARP);
+    /// assert_eq!(DegreeAlteration::Sharp.apply(F), F_SHARP);
+    /// assert_eq!(DegreeAlteration::None.apply(E), E);
+    /// assert_eq!(DegreeAlteration::Flat.apply(C), B); // C♭
+    /// ```
+    pub const fn apply(&self, pitch_class: PitchClass) -> PitchClass {
+        let value = pitch_class.value() as i8 + SEMITONES_IN_OCTAVE as i8 + self.semitone_offset();
+        PitchClass::new(value as u8)
+    }
+
+    /// Apply the alteration to a semitone offset.
+    ///
+    /// Follows the wrapping arithmetic of `Semitone`, so flattening 0 wraps.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{DegreeAlteration, Semitone};
+    ///
+    /// let major_third = Semitone::new(4);
+    /// assert_eq!(DegreeAlteration::Flat.apply_semitone(major_third), Semitone::new(3));
+    /// assert_eq!(DegreeAlteration::Sharp.apply_semitone(major_third), Semitone::new(5));
+    /// ```
+    pub fn apply_semitone(&self, semitone: Semitone) -> Semitone {
+        match self {
+            DegreeAlteration::None => semitone,
+            DegreeAlteration::Sharp => semitone + 1u8,
+            DegreeAlteration::Flat => semitone - 1u8,
+        }
+    }
+
     /// Check if this is a sharp alteration.
     ///
     /// # Examples
@@ -279,6 +317,48 @@ mod tests {
         assert_eq!(DegreeAlteration::default(), DegreeAlteration::None);
     }
 
+    #[test]
+    fn test_apply() {
+        use crate::pitch_class::{B, C, D_SHARP, E, F, F_SHARP};
+
+        assert_eq!(DegreeAlteration::Flat.apply(E), D_SHARP);
+        assert_eq!(DegreeAlteration::Sharp.apply(F), F_SHARP);
+        assert_eq!(DegreeAlteration::Flat.apply(C), B);
+        assert_eq!(DegreeAlteration::Sharp.apply(B), C);
+
+        for pitch_class in PitchClass::all() {
+            assert_eq!(DegreeAlteration::None.apply(pitch_class), pitch_class);
+            assert_eq!(
+                DegreeAlteration::Flat.apply(DegreeAlteration::Sharp.apply(pitch_class)),
+                pitch_class
+            );
+        }
+    }
+
+    #[test]
+    fn test_apply_semitone() {
+        let seventh = Semitone::new(11);
+        assert_eq!(
+            DegreeAlteration::Flat.apply_semitone(seventh),
+            Semitone::new(10)
+        );
+        assert_eq!(
+            DegreeAlteration::Sharp.apply_semitone(seventh),
+            Semitone::new(12)
+        );
+        assert_eq!(DegreeAlteration::None.apply_semitone(seventh), seventh);
+
+        // Matches semitone_offset arithmetic
+        for alteration in [
+            DegreeAlteration::None,
+            DegreeAlteration::Flat,
+            DegreeAlteration::Sharp,
+        ] {
+            let altered = alteration.apply_semitone(Semitone::new(7));
+            assert_eq!(u8::from(altered) as i8, 7 + alteration.semitone_offset());
+        }
+    }
+
     #[test]
     fn test_musical_calculations() {
         // Test practical usage in musical calculations
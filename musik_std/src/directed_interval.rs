@@ -0,0 +1,153 @@
+//! Intervals with an explicit direction.
+//!
+//! This module provides the `DirectedInterval` struct, which pairs a size in
+//! semitones with a direction so that descending motion can be expressed
+//! directly in voice-leading and counterpoint code.
+
+use crate::note::Note;
+use crate::semitone::Semitone;
+
+/// An interval moving up or down by a number of semitones.
+///
+/// # Examples
+/// ```
+/// use musik_std::{DirectedInterval, Note, Semitone};
+///
+/// let fifth_down = DirectedInterval::descending(Semitone::new(7));
+/// assert_eq!(fifth_down.apply(Note::new(67)), Note::new(60)); // G4 -> C4
+/// assert_eq!(fifth_down.semitones(), -7);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DirectedInterval {
+    /// The size of the interval.
+    pub interval: Semitone,
+    /// Whether the interval moves up (`true`) or down (`false`).
+    pub ascending: bool,
+}
+
+impl DirectedInterval {
+    /// Creates a directed interval from a size and a direction.
+    pub const fn new(interval: Semitone, ascending: bool) -> Self {
+        Self {
+            interval,
+            ascending,
+        }
+    }
+
+    /// Creates an interval moving up.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{DirectedInterval, Note, Semitone};
+    ///
+    /// let major_third_up = DirectedInterval::ascending(Semitone::new(4));
+    /// assert_eq!(major_third_up.apply(Note::new(60)), Note::new(64));
+    /// ```
+    pub const fn ascending(interval: Semitone) -> Self {
+        Self::new(interval, true)
+    }
+
+    /// Creates an interval moving down.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{DirectedInterval, Note, Semitone};
+    ///
+    /// let minor_third_down = DirectedInterval::descending(Semitone::new(3));
+    /// assert_eq!(minor_third_down.apply(Note::new(60)), Note::new(57));
+    /// ```
+    pub const fn descending(interval: Semitone) -> Self {
+        Self::new(interval, false)
+    }
+
+    /// Transposes a note up or down by this interval.
+    ///
+    /// The result saturates at the ends of the note range, like the `Add` and
+    /// `Sub` implementations of [`Note`].
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{DirectedInterval, Note, Semitone};
+    ///
+    /// let octave_down = DirectedInterval::descending(Semitone::new(12));
+    /// assert_eq!(octave_down.apply(Note::new(72)), Note::new(60));
+    /// assert_eq!(octave_down.apply(Note::new(5)), Note::new(0)); // Saturates
+    /// ```
+    pub fn apply(&self, note: Note) -> Note {
+        if self.ascending {
+            note + self.interval
+        } else {
+            note - self.interval
+        }
+    }
+
+    /// Returns the signed size of the interval: positive up, negative down.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{DirectedInterval, Semitone};
+    ///
+    /// assert_eq!(DirectedInterval::ascending(Semitone::new(7)).semitones(), 7);
+    /// assert_eq!(DirectedInterval::descending(Semitone::new(7)).semitones(), -7);
+    /// ```
+    pub fn semitones(&self) -> i16 {
+        let size = u8::from(self.interval) as i16;
+        if self.ascending {
+            size
+        } else {
+            -size
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_directed_interval_apply() {
+        let g4 = Note::new(67);
+        let c4 = Note::new(60);
+
+        let fifth_down = DirectedInterval::descending(Semitone::new(7));
+        let fifth_up = DirectedInterval::ascending(Semitone::new(7));
+        assert_eq!(fifth_down.apply(g4), c4);
+        assert_eq!(fifth_up.apply(c4), g4);
+
+        // Unison leaves the note unchanged in either direction
+        assert_eq!(DirectedInterval::ascending(Semitone::new(0)).apply(c4), c4);
+        assert_eq!(DirectedInterval::descending(Semitone::new(0)).apply(c4), c4);
+
+        // Saturation at the ends of the range
+        assert_eq!(fifth_down.apply(Note::new(3)), Note::new(0));
+        assert_eq!(fifth_up.apply(Note::new(250)), Note::new(u8::MAX));
+    }
+
+    #[test]
+    fn test_directed_interval_semitones() {
+        assert_eq!(
+            DirectedInterval::descending(Semitone::new(7)).semitones(),
+            -7
+        );
+        assert_eq!(DirectedInterval::ascending(Semitone::new(7)).semitones(), 7);
+        assert_eq!(
+            DirectedInterval::new(Semitone::new(0), false).semitones(),
+            0
+        );
+        assert_eq!(
+            DirectedInterval::descending(Semitone::new(u8::MAX)).semitones(),
+            -255
+        );
+    }
+
+    #[test]
+    fn test_directed_interval_matches_semitones() {
+        for value in 0..=24u8 {
+            for ascending in [true, false] {
+                let interval = DirectedInterval::new(Semitone::new(value), ascending);
+                let moved = interval.apply(Note::new(60)).semitone() as i16 - 60;
+                assert_eq!(moved, interval.semitones());
+            }
+        }
+    }
+}
@@ -3,6 +3,8 @@
 //! This module provides the `FormulaDegree` type for representing degrees in chord formulas,
 //! supporting extended harmony (9ths, 11ths, 13ths) and alterations (flat, sharp).
 
+use crate::note::Note;
+use crate::semitone::SEMITONES_IN_OCTAVE;
 use std::fmt;
 
 /// Represents a degree in a chord formula, supporting extended harmony (9, 11, 13, etc.)
@@ -180,6 +182,61 @@ impl FormulaDegree {
         Some(result)
     }
 
+    /// Get the note this degree lands on above `root`, within the root's octave.
+    ///
+    /// Extended degrees are reduced like [`to_semitone_offset`](Self::to_semitone_offset),
+    /// so a 9th is placed a major 2nd above the root. Returns `None` for unsupported
+    /// degrees or when the resulting note would exceed the `Note` range.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{FormulaDegree, Note};
+    ///
+    /// let c4 = Note::new(60);
+    /// assert_eq!(FormulaDegree::natural(3).note_from(c4), Some(Note::new(64))); // E4
+    /// assert_eq!(FormulaDegree::natural(9).note_from(c4), Some(Note::new(62))); // D4
+    /// assert_eq!(FormulaDegree::natural(5).note_from(Note::new(250)), None);
+    /// ```
+    pub fn note_from(&self, root: Note) -> Option<Note> {
+        let offset = self.to_semitone_offset()?;
+        root.semitone().checked_add(offset).map(Note::new)
+    }
+
+    /// Get the note this degree lands on above `root`, keeping extended degrees
+    /// in their upper octave.
+    ///
+    /// Degrees 8-14 are placed one octave above the root and degree 15 two octaves
+    /// above, so tensions such as 9, 11, and 13 sit above the chord tones. The
+    /// alteration applies to the compound interval, so a ♭8 lands a major 7th above
+    /// the root rather than wrapping into the next octave. Returns `None` for
+    /// unsupported degrees or when the resulting note would exceed the `Note` range.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{FormulaDegree, Note};
+    ///
+    /// let c4 = Note::new(60);
+    /// assert_eq!(FormulaDegree::natural(9).note_from_extended(c4), Some(Note::new(74))); // D5
+    /// assert_eq!(FormulaDegree::sharp(11).note_from_extended(c4), Some(Note::new(78))); // F♯5
+    /// assert_eq!(FormulaDegree::natural(3).note_from_extended(c4), Some(Note::new(64))); // E4
+    /// ```
+    pub fn note_from_extended(&self, root: Note) -> Option<Note> {
+        let natural_offset = FormulaDegree::Natural(self.base_degree()).to_semitone_offset()?;
+        let octaves = (self.base_degree() - 1) / 7;
+        let compound_offset =
+            (octaves * SEMITONES_IN_OCTAVE + natural_offset) as i16 + self.alteration() as i16;
+
+        // Only a flattened root falls below it; keep it in the root's octave
+        let extended_offset = if compound_offset < 0 {
+            compound_offset + SEMITONES_IN_OCTAVE as i16
+        } else {
+            compound_offset
+        };
+        root.semitone()
+            .checked_add(u8::try_from(extended_offset).ok()?)
+            .map(Note::new)
+    }
+
     /// Get the symbol representation of the formula degree
     ///
     /// # Examples
@@ -539,4 +596,91 @@ mod tests {
         assert!(!SHARP_ELEVEN.is_chord_tone());
         assert!(SHARP_ELEVEN.is_tension());
     }
+
+    #[test]
+    fn test_formula_degree_note_from() {
+        let c4 = Note::new(60);
+
+        assert_eq!(FormulaDegree::natural(1).note_from(c4), Some(c4));
+        assert_eq!(FormulaDegree::flat(3).note_from(c4), Some(Note::new(63)));
+        assert_eq!(FormulaDegree::flat(7).note_from(c4), Some(Note::new(70)));
+        assert_eq!(FormulaDegree::natural(9).note_from(c4), Some(Note::new(62))); // D4
+        assert_eq!(FormulaDegree::flat(13).note_from(c4), Some(Note::new(68)));
+
+        // Unsupported degrees and overflow
+        assert_eq!(FormulaDegree::natural(16).note_from(c4), None);
+        assert_eq!(FormulaDegree::natural(7).note_from(Note::new(250)), None);
+        assert_eq!(
+            FormulaDegree::natural(1).note_from(Note::new(255)),
+            Some(Note::new(255))
+        );
+    }
+
+    #[test]
+    fn test_formula_degree_note_from_extended() {
+        let c4 = Note::new(60);
+
+        // Basic degrees match note_from
+        for degree in 1..=7 {
+            let natural = FormulaDegree::natural(degree);
+            assert_eq!(natural.note_from_extended(c4), natural.note_from(c4));
+        }
+
+        // Tensions are placed an octave up
+        assert_eq!(
+            FormulaDegree::natural(9).note_from_extended(c4),
+            Some(Note::new(74))
+        ); // D5
+        assert_eq!(
+            FormulaDegree::flat(9).note_from_extended(c4),
+            Some(Note::new(73))
+        );
+        assert_eq!(
+            FormulaDegree::natural(11).note_from_extended(c4),
+            Some(Note::new(77))
+        );
+        assert_eq!(
+            FormulaDegree::natural(13).note_from_extended(c4),
+            Some(Note::new(81))
+        );
+        assert_eq!(
+            FormulaDegree::natural(8).note_from_extended(c4),
+            Some(Note::new(72))
+        );
+
+        // Double octave
+        assert_eq!(
+            FormulaDegree::natural(15).note_from_extended(c4),
+            Some(Note::new(84))
+        );
+
+        // Altered octave degrees keep their compound interval instead of wrapping
+        assert_eq!(
+            FormulaDegree::flat(8).note_from_extended(c4),
+            Some(Note::new(71))
+        ); // B4
+        assert_eq!(
+            FormulaDegree::flat(15).note_from_extended(c4),
+            Some(Note::new(83))
+        ); // B5
+        assert_eq!(
+            FormulaDegree::sharp(7).note_from_extended(c4),
+            Some(Note::new(72))
+        ); // C5
+        assert_eq!(
+            FormulaDegree::sharp(14).note_from_extended(c4),
+            Some(Note::new(84))
+        ); // C6
+        assert_eq!(
+            FormulaDegree::flat(1).note_from_extended(c4),
+            Some(Note::new(71))
+        );
+
+        // Overflow and unsupported degrees
+        assert_eq!(
+            FormulaDegree::natural(9).note_from_extended(Note::new(245)),
+            None
+        );
+        assert_eq!(FormulaDegree::natural(16).note_from_extended(c4), None);
+    }
 }
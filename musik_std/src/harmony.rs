@@ -0,0 +1,674 @@
+//! Reharmonization helpers.
+//!
+//! This module provides functions for substituting chords in a progression,
+//! such as the tritone substitution for dominant chords, resolving tritones,
+//! finding modulations between keys, metrics for comparing chord voicings, and
+//! voice-leading checks.
+
+use crate::chord_formula::ChordFormula;
+use crate::degree_alteration::DegreeAlteration;
+use crate::note::Note;
+use crate::pitch_class::PitchClass;
+use crate::scale_formula::ScaleFormula;
+use crate::semitone::SEMITONES_IN_OCTAVE;
+
+/// Semitones in a tritone (augmented 4th / diminished 5th).
+pub(crate) const TRITONE: u8 = 6;
+
+/// Semitones from a key's tonic up to its dominant.
+const DOMINANT: u8 = 7;
+
+/// Semitones in a perfect fifth.
+const PERFECT_FIFTH: u8 = 7;
+
+/// Semitones from a key's tonic up to its subdominant.
+const SUBDOMINANT: u8 = 5;
+
+/// Semitones from a major key's tonic up to the tonic of its relative minor.
+const RELATIVE_MINOR: u8 = 9;
+
+/// Scale positions (0-based) of the predominant triads, ii and IV, in preference order.
+const PREDOMINANT_POSITIONS: [usize; 2] = [1, 3];
+
+/// Returns the root of the tritone substitute for a dominant chord on `root`.
+///
+/// A dominant 7th shares its 3rd and ♭7th with the dominant a tritone away, so
+/// the two are interchangeable: D♭7 substitutes for G7. Applying the substitution
+/// twice returns the original root.
+///
+/// # Examples
+/// ```
+/// use musik_std::{tritone_sub, C_SHARP, G};
+///
+/// assert_eq!(tritone_sub(G), C_SHARP); // D♭7 for G7
+/// assert_eq!(tritone_sub(C_SHARP), G);
+/// ```
+pub const fn tritone_sub(root: PitchClass) -> PitchClass {
+    PitchClass::new(root.value() + TRITONE)
+}
+
+/// Replaces every dominant chord in a progression with its tritone substitute.
+///
+/// Each chord is a `(root, formula)` pair. A chord has dominant function when
+/// it holds a major 3rd and a minor 7th, so 9th, 13th, and altered dominants
+/// qualify along with the plain dominant 7th. Dominant chords move to the root
+/// given by [`tritone_sub`] and keep their formula; every other chord is left
+/// unchanged.
+///
+/// # Examples
+/// ```
+/// use musik_std::{apply_tritone_subs, ChordFormula, C, C_SHARP, D, G};
+///
+/// let ii_v_i = [
+///     (D, ChordFormula::minor_seventh()),
+///     (G, ChordFormula::dominant_seventh()),
+///     (C, ChordFormula::major_seventh()),
+/// ];
+/// let reharmonized = apply_tritone_subs(&ii_v_i);
+/// assert_eq!(reharmonized[1], (C_SHARP, ChordFormula::dominant_seventh())); // D♭7
+/// ```
+pub fn apply_tritone_subs(
+    chords: &[(PitchClass, ChordFormula)],
+) -> Vec<(PitchClass, ChordFormula)> {
+    chords
+        .iter()
+        .map(|&(root, formula)| {
+            if has_dominant_function(formula) {
+                (tritone_sub(root), formula)
+            } else {
+                (root, formula)
+            }
+        })
+        .collect()
+}
+
+/// Checks for the major 3rd and minor 7th that make up a dominant's tritone.
+fn has_dominant_function(formula: ChordFormula) -> bool {
+    formula.has_degree(3, DegreeAlteration::None) && formula.has_degree(7, DegreeAlteration::Flat)
+}
+
+/// Transposes a progression from one key to another.
+///
+/// Every root moves up by the interval from `from_key` to `to_key`, reduced to
+/// the octave, and every formula is kept, so chord qualities are unchanged.
+///
+/// # Examples
+/// ```
+/// use musik_std::{transpose_progression, ChordFormula, C, F, G};
+///
+/// let chords = [(C, ChordFormula::major_triad()), (G, ChordFormula::dominant_seventh())];
+/// assert_eq!(
+///     transpose_progression(&chords, C, F),
+///     vec![(F, ChordFormula::major_triad()), (C, ChordFormula::dominant_seventh())]
+/// );
+/// ```
+pub fn transpose_progression(
+    chords: &[(PitchClass, ChordFormula)],
+    from_key: PitchClass,
+    to_key: PitchClass,
+) -> Vec<(PitchClass, ChordFormula)> {
+    let interval = (to_key.value() + SEMITONES_IN_OCTAVE - from_key.value()) % SEMITONES_IN_OCTAVE;
+    chords
+        .iter()
+        .map(|&(root, formula)| (PitchClass::new(root.value() + interval), formula))
+        .collect()
+}
+
+/// Resolves a tritone inward by contrary semitone motion.
+///
+/// `a` is the lower note of the diminished 5th, usually the leading tone,
+/// and moves up; `b` is the upper note, usually the 7th of the dominant, and
+/// moves down. In G7 the tritone B-F resolves to C-E.
+///
+/// # Examples
+/// ```
+/// use musik_std::{resolve_tritone, B, C, E, F};
+///
+/// assert_eq!(resolve_tritone(B, F), (C, E));
+/// ```
+pub const fn resolve_tritone(a: PitchClass, b: PitchClass) -> (PitchClass, PitchClass) {
+    (
+        PitchClass::new(a.value() + 1),
+        PitchClass::new(b.value() + SEMITONES_IN_OCTAVE - 1),
+    )
+}
+
+/// Returns the five keys closely related to the major key on `tonic`.
+///
+/// These are its relative minor, then the dominant major key and its relative
+/// minor, then the subdominant major key and its relative minor: the neighbours
+/// one step either way on the circle of fifths.
+///
+/// # Examples
+/// ```
+/// use musik_std::{related_keys, ScaleFormula, A, C, D, E, F, G};
+///
+/// let (major, minor) = (ScaleFormula::major(), ScaleFormula::minor());
+/// assert_eq!(
+///     related_keys(C),
+///     vec![(A, minor), (G, major), (E, minor), (F, major), (D, minor)]
+/// );
+/// ```
+pub fn related_keys(tonic: PitchClass) -> Vec<(PitchClass, ScaleFormula)> {
+    let relative_minor = |major: PitchClass| {
+        (
+            PitchClass::new(major.value() + RELATIVE_MINOR),
+            ScaleFormula::minor(),
+        )
+    };
+    let dominant = PitchClass::new(tonic.value() + DOMINANT);
+    let subdominant = PitchClass::new(tonic.value() + SUBDOMINANT);
+
+    vec![
+        relative_minor(tonic),
+        (dominant, ScaleFormula::major()),
+        relative_minor(dominant),
+        (subdominant, ScaleFormula::major()),
+        relative_minor(subdominant),
+    ]
+}
+
+/// Finds a short chord sequence that modulates from one key to another.
+///
+/// The path starts with a pivot chord, a triad diatonic to both keys, chosen
+/// among the predominants of the new key (ii, then IV) when possible. It then
+/// moves to the dominant seventh of the new key and resolves to its tonic
+/// triad. Without a common triad the pivot is left out and the modulation is
+/// direct. Returns an empty path if the new key has no tonic triad.
+///
+/// # Examples
+/// ```
+/// use musik_std::{modulation_path, ChordFormula, ScaleFormula, A, C, D, G};
+///
+/// let major = ScaleFormula::major();
+/// let path = modulation_path(C, major, G, major);
+/// assert_eq!(
+///     path,
+///     vec![
+///         (A, ChordFormula::minor_triad()),      // vi in C = ii in G
+///         (D, ChordFormula::dominant_seventh()), // V7 in G
+///         (G, ChordFormula::major_triad()),      // I in G
+///     ]
+/// );
+/// ```
+pub fn modulation_path(
+    from_root: PitchClass,
+    from_scale: ScaleFormula,
+    to_root: PitchClass,
+    to_scale: ScaleFormula,
+) -> Vec<(PitchClass, ChordFormula)> {
+    let Some(tonic_quality) = to_scale.triad_quality(1) else {
+        return Vec::new();
+    };
+
+    let source = diatonic_triads(from_root, from_scale);
+    let target = diatonic_triads(to_root, to_scale);
+
+    let predominant = PREDOMINANT_POSITIONS
+        .iter()
+        .filter_map(|&position| target.get(position).copied().flatten())
+        .find(|chord| source.contains(&Some(*chord)));
+    let pivot = predominant.or_else(|| {
+        target
+            .iter()
+            .skip(1)
+            .flatten()
+            .find(|chord| source.contains(&Some(**chord)))
+            .copied()
+    });
+
+    let dominant = (
+        PitchClass::new(to_root.value() + DOMINANT),
+        ChordFormula::dominant_seventh(),
+    );
+    let tonic = (to_root, tonic_quality.triad());
+
+    pivot.into_iter().chain([dominant, tonic]).collect()
+}
+
+/// Gets the triad on each first-octave degree of the scale built on `root`.
+///
+/// Degrees whose stacked thirds do not form a tertian triad are `None`, so the
+/// position in the result still matches the scale degree.
+fn diatonic_triads(
+    root: PitchClass,
+    scale: ScaleFormula,
+) -> Vec<Option<(PitchClass, ChordFormula)>> {
+    scale
+        .iter_semitones()
+        .take_while(|&semitone| semitone < SEMITONES_IN_OCTAVE)
+        .enumerate()
+        .map(|(index, semitone)| {
+            scale
+                .triad_quality(index as u8 + 1)
+                .map(|quality| (PitchClass::new(root.value() + semitone), quality.triad()))
+        })
+        .collect()
+}
+
+/// Get the distance in semitones between the lowest and highest note of a voicing.
+///
+/// The notes may be given in any order. An empty voicing has a spread of 0.
+///
+/// # Examples
+/// ```
+/// use musik_std::{voicing_spread, Note};
+///
+/// let close = [Note::new(60), Note::new(64), Note::new(67)]; // C4 E4 G4
+/// let open = [Note::new(48), Note::new(55), Note::new(64)]; // C3 G3 E4
+/// assert_eq!(voicing_spread(&close), 7);
+/// assert_eq!(voicing_spread(&open), 16);
+/// ```
+pub fn voicing_spread(notes: &[Note]) -> u8 {
+    let lowest = notes.iter().map(|note| note.semitone()).min();
+    let highest = notes.iter().map(|note| note.semitone()).max();
+
+    match (lowest, highest) {
+        (Some(lowest), Some(highest)) => highest - lowest,
+        _ => 0,
+    }
+}
+
+/// Get the average interval in semitones between adjacent notes of a voicing.
+///
+/// Adjacent means neighbouring once the notes are stacked from lowest to
+/// highest, so the notes may be given in any order. Voicings with fewer than
+/// two notes have a density of 0.0.
+///
+/// # Examples
+/// ```
+/// use musik_std::{voicing_density, Note};
+///
+/// let close = [Note::new(60), Note::new(64), Note::new(67)]; // Gaps of 4 and 3
+/// assert_eq!(voicing_density(&close), 3.5);
+/// ```
+pub fn voicing_density(notes: &[Note]) -> f64 {
+    if notes.len() < 2 {
+        return 0.0;
+    }
+
+    // The gaps between stacked notes always sum to the spread
+    voicing_spread(notes) as f64 / (notes.len() - 1) as f64
+}
+
+/// Check if any two voices move in parallel perfect fifths between two voicings.
+///
+/// Voices are paired by index, so `from[i]` moves to `to[i]`; extra voices in
+/// the longer voicing are ignored. Two voices are in parallel fifths when both
+/// move in the same direction and are a perfect fifth (or compound fifth)
+/// apart before and after the move.
+///
+/// # Examples
+/// ```
+/// use musik_std::{has_parallel_fifths, Note};
+///
+/// let c_g = [Note::new(48), Note::new(55)]; // C3 G3
+/// let d_a = [Note::new(50), Note::new(57)]; // D3 A3
+/// let b_g = [Note::new(47), Note::new(55)]; // B2 G3
+/// assert!(has_parallel_fifths(&c_g, &d_a));
+/// assert!(!has_parallel_fifths(&c_g, &b_g));
+/// ```
+pub fn has_parallel_fifths(from: &[Note], to: &[Note]) -> bool {
+    has_parallel_interval(from, to, PERFECT_FIFTH)
+}
+
+/// Check if any two voices move in parallel octaves between two voicings.
+///
+/// Voices are paired by index as in [`has_parallel_fifths`]. Unisons and
+/// compound octaves count as octaves.
+///
+/// # Examples
+/// ```
+/// use musik_std::{has_parallel_octaves, Note};
+///
+/// let c_c = [Note::new(48), Note::new(60)]; // C3 C4
+/// let d_d = [Note::new(50), Note::new(62)]; // D3 D4
+/// let b_d = [Note::new(47), Note::new(62)]; // B2 D4
+/// assert!(has_parallel_octaves(&c_c, &d_d));
+/// assert!(!has_parallel_octaves(&c_c, &b_d));
+/// ```
+pub fn has_parallel_octaves(from: &[Note], to: &[Note]) -> bool {
+    has_parallel_interval(from, to, 0)
+}
+
+/// Check if two voices move in the same direction while keeping `interval`
+/// (modulo the octave) between them.
+fn has_parallel_interval(from: &[Note], to: &[Note], interval: u8) -> bool {
+    let voices: Vec<(i16, i16)> = from
+        .iter()
+        .zip(to)
+        .map(|(start, end)| (start.semitone() as i16, end.semitone() as i16))
+        .collect();
+    let is_interval = |first: i16, second: i16| {
+        (first - second).unsigned_abs() % SEMITONES_IN_OCTAVE as u16 == interval as u16
+    };
+
+    voices.iter().enumerate().any(|(index, &(start, end))| {
+        let motion = (end - start).signum();
+        voices[index + 1..].iter().any(|&(other_start, other_end)| {
+            motion != 0
+                && motion == (other_end - other_start).signum()
+                && is_interval(start, other_start)
+                && is_interval(end, other_end)
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::note::notes;
+    use crate::pitch_class::{A, B, C, C_SHARP, D, E, F, F_SHARP, G};
+
+    #[test]
+    fn test_tritone_sub() {
+        assert_eq!(tritone_sub(G), C_SHARP);
+        assert_eq!(tritone_sub(C), F_SHARP);
+
+        // The substitution is its own inverse
+        for root in PitchClass::all() {
+            assert_eq!(tritone_sub(tritone_sub(root)), root);
+            assert_ne!(tritone_sub(root), root);
+        }
+    }
+
+    #[test]
+    fn test_apply_tritone_subs() {
+        use crate::pitch_class::{A_FLAT, E_FLAT};
+
+        let ii_v_i = [
+            (D, ChordFormula::minor_seventh()),
+            (G, ChordFormula::dominant_seventh()),
+            (C, ChordFormula::major_seventh()),
+        ];
+        assert_eq!(
+            apply_tritone_subs(&ii_v_i),
+            vec![
+                (D, ChordFormula::minor_seventh()),
+                (C_SHARP, ChordFormula::dominant_seventh()),
+                (C, ChordFormula::major_seventh()),
+            ]
+        );
+
+        // Substituting twice restores the progression
+        assert_eq!(
+            apply_tritone_subs(&apply_tritone_subs(&ii_v_i)),
+            ii_v_i.to_vec()
+        );
+
+        // Extended and altered dominants are substituted too
+        let extended = [
+            (G, ChordFormula::dominant_ninth()),
+            (D, ChordFormula::dominant_thirteenth()),
+            (A, ChordFormula::altered_dominant()),
+        ];
+        assert_eq!(
+            apply_tritone_subs(&extended),
+            vec![
+                (C_SHARP, ChordFormula::dominant_ninth()),
+                (A_FLAT, ChordFormula::dominant_thirteenth()),
+                (E_FLAT, ChordFormula::altered_dominant()),
+            ]
+        );
+
+        // Chords without a major 3rd and minor 7th are left alone
+        let others = [
+            (G, ChordFormula::major_triad()),
+            (G, ChordFormula::sixth()),
+            (G, ChordFormula::major_ninth()),
+            (G, ChordFormula::minor_seventh()),
+            (G, ChordFormula::half_diminished_seventh()),
+        ];
+        assert_eq!(apply_tritone_subs(&others), others.to_vec());
+        assert!(apply_tritone_subs(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_tritone_sub_shares_guide_tones() {
+        // The 3rd and ♭7th of G7 are the ♭7th and 3rd of D♭7
+        let dominant = ChordFormula::dominant_seventh();
+        let guide_tones = |root: PitchClass| {
+            let mut tones: Vec<PitchClass> = dominant
+                .semitone_offsets()
+                .into_iter()
+                .filter(|&offset| offset == 4 || offset == 10)
+                .map(|offset| PitchClass::new(root.value() + offset))
+                .collect();
+            tones.sort();
+            tones
+        };
+
+        for root in PitchClass::all() {
+            assert_eq!(guide_tones(root), guide_tones(tritone_sub(root)));
+        }
+    }
+
+    #[test]
+    fn test_resolve_tritone() {
+        use crate::pitch_class::{A, A_SHARP, B, C, E, F, F_SHARP, G};
+
+        // V7 to I in C major and in F major
+        assert_eq!(resolve_tritone(B, F), (C, E));
+        assert_eq!(resolve_tritone(E, A_SHARP), (F, A));
+
+        // Wraps around the octave: D7 to G resolves C down to B
+        assert_eq!(resolve_tritone(F_SHARP, C), (G, B));
+
+        // Every tritone a dominant 7th reports resolves to the tonic's root and 3rd
+        for root in PitchClass::all() {
+            let tonic = PitchClass::new(root.value() + SEMITONES_IN_OCTAVE - DOMINANT);
+            for (a, b) in ChordFormula::dominant_seventh().tritones(root) {
+                let (up, down) = resolve_tritone(a, b);
+                assert_eq!(up, tonic);
+                assert_eq!(down, PitchClass::new(tonic.value() + 4));
+            }
+        }
+    }
+
+    #[test]
+    fn test_transpose_progression() {
+        use crate::pitch_class::{B_FLAT, C, D, E_FLAT, F, G};
+
+        let ii_v_i = [
+            (D, ChordFormula::minor_seventh()),
+            (G, ChordFormula::dominant_seventh()),
+            (C, ChordFormula::major_seventh()),
+        ];
+        let in_f = transpose_progression(&ii_v_i, C, F);
+        assert_eq!(
+            in_f,
+            vec![
+                (G, ChordFormula::minor_seventh()),
+                (C, ChordFormula::dominant_seventh()),
+                (F, ChordFormula::major_seventh()),
+            ]
+        );
+
+        // Every root moves up a perfect fourth and every quality is kept
+        for (&(root, formula), &(moved, moved_formula)) in ii_v_i.iter().zip(&in_f) {
+            assert_eq!(moved, PitchClass::new(root.value() + 5));
+            assert_eq!(moved_formula, formula);
+        }
+
+        // Moving down to a lower-named key wraps around the octave
+        assert_eq!(
+            transpose_progression(&ii_v_i, F, E_FLAT)[0],
+            (C, ChordFormula::minor_seventh())
+        );
+        assert_eq!(transpose_progression(&in_f, F, B_FLAT)[2].0, B_FLAT);
+        assert_eq!(transpose_progression(&ii_v_i, C, C), ii_v_i.to_vec());
+    }
+
+    #[test]
+    fn test_voicing_spread() {
+        let close = notes(&[60, 64, 67]);
+        let open = notes(&[48, 55, 64]);
+        assert_eq!(voicing_spread(&close), 7);
+        assert_eq!(voicing_spread(&open), 16);
+        assert!(voicing_spread(&close) < voicing_spread(&open));
+
+        // Order does not matter
+        assert_eq!(voicing_spread(&notes(&[67, 60, 64])), 7);
+
+        assert_eq!(voicing_spread(&[]), 0);
+        assert_eq!(voicing_spread(&notes(&[60])), 0);
+        assert_eq!(voicing_spread(&notes(&[0, 127])), 127);
+    }
+
+    #[test]
+    fn test_voicing_density() {
+        assert_eq!(voicing_density(&notes(&[60, 64, 67])), 3.5);
+        assert_eq!(voicing_density(&notes(&[48, 55, 64])), 8.0);
+        assert_eq!(voicing_density(&notes(&[60, 64, 67, 71])), 11.0 / 3.0);
+
+        // Stacked fourths are evenly spaced
+        assert_eq!(voicing_density(&notes(&[72, 62, 67, 57])), 5.0);
+
+        assert_eq!(voicing_density(&[]), 0.0);
+        assert_eq!(voicing_density(&notes(&[60])), 0.0);
+        assert_eq!(voicing_density(&notes(&[60, 60])), 0.0);
+    }
+
+    #[test]
+    fn test_has_parallel_fifths() {
+        // C-G to D-A
+        assert!(has_parallel_fifths(&notes(&[48, 55]), &notes(&[50, 57])));
+        // Descending, and as a compound fifth
+        assert!(has_parallel_fifths(&notes(&[50, 69]), &notes(&[48, 67])));
+        // Voices in any order, inside a larger voicing
+        assert!(has_parallel_fifths(
+            &notes(&[67, 64, 60]),
+            &notes(&[69, 65, 62])
+        ));
+
+        // Contrary motion into a fifth
+        assert!(!has_parallel_fifths(&notes(&[48, 55]), &notes(&[47, 55])));
+        assert!(!has_parallel_fifths(&notes(&[48, 55]), &notes(&[43, 62])));
+        // Repeated fifth is not motion
+        assert!(!has_parallel_fifths(&notes(&[48, 55]), &notes(&[48, 55])));
+        // Fifth to a different interval
+        assert!(!has_parallel_fifths(&notes(&[48, 55]), &notes(&[50, 56])));
+        // A tritone is not a perfect fifth
+        assert!(!has_parallel_fifths(&notes(&[48, 54]), &notes(&[50, 56])));
+
+        assert!(!has_parallel_fifths(&[], &[]));
+        assert!(!has_parallel_fifths(&notes(&[48]), &notes(&[50])));
+    }
+
+    #[test]
+    fn test_has_parallel_octaves() {
+        assert!(has_parallel_octaves(&notes(&[48, 60]), &notes(&[50, 62])));
+        // Double octave and unison
+        assert!(has_parallel_octaves(&notes(&[48, 72]), &notes(&[47, 71])));
+        assert!(has_parallel_octaves(&notes(&[60, 60]), &notes(&[62, 62])));
+
+        // Contrary and oblique motion
+        assert!(!has_parallel_octaves(&notes(&[48, 60]), &notes(&[50, 50])));
+        assert!(!has_parallel_octaves(&notes(&[48, 60]), &notes(&[48, 72])));
+
+        // Fifths are not octaves, and vice versa
+        assert!(!has_parallel_octaves(&notes(&[48, 55]), &notes(&[50, 57])));
+        assert!(!has_parallel_fifths(&notes(&[48, 60]), &notes(&[50, 62])));
+    }
+
+    #[test]
+    fn test_related_keys() {
+        let major = ScaleFormula::major();
+        let minor = ScaleFormula::minor();
+
+        let keys = related_keys(C);
+        assert_eq!(keys.len(), 5);
+        for key in [(G, major), (F, major), (A, minor), (E, minor), (D, minor)] {
+            assert!(keys.contains(&key));
+        }
+
+        assert_eq!(
+            related_keys(D),
+            vec![
+                (B, minor),
+                (A, major),
+                (F_SHARP, minor),
+                (G, major),
+                (E, minor)
+            ]
+        );
+
+        // Each related key differs from the tonic key by at most one accidental
+        for tonic in PitchClass::all() {
+            let home = major.pitch_classes(tonic);
+            for (root, scale) in related_keys(tonic) {
+                let shared = scale
+                    .pitch_classes(root)
+                    .iter()
+                    .filter(|pitch_class| home.contains(pitch_class))
+                    .count();
+                assert!(shared >= 6);
+            }
+        }
+    }
+
+    #[test]
+    fn test_modulation_path_c_to_g() {
+        let major = ScaleFormula::major();
+        let path = modulation_path(C, major, G, major);
+
+        assert_eq!(path.len(), 3);
+
+        // The pivot is diatonic to both keys
+        let (pivot_root, pivot) = path[0];
+        for pitch_class in pivot.pitch_classes(pivot_root) {
+            assert!(major.contains_pitch_class(C, pitch_class));
+            assert!(major.contains_pitch_class(G, pitch_class));
+        }
+
+        assert_eq!(path[1], (D, ChordFormula::dominant_seventh()));
+        assert_eq!(path[2], (G, ChordFormula::major_triad()));
+    }
+
+    #[test]
+    fn test_modulation_path_pivot_choice() {
+        let major = ScaleFormula::major();
+        let minor = ScaleFormula::minor();
+
+        // Neither predominant of F major (Gm, B♭) is in C major, so another
+        // common triad is used
+        let path = modulation_path(C, major, F, major);
+        assert_eq!(
+            path,
+            vec![
+                (A, ChordFormula::minor_triad()),
+                (C, ChordFormula::dominant_seventh()),
+                (F, ChordFormula::major_triad()),
+            ]
+        );
+
+        // The ii of A minor is the vii of C major
+        let path = modulation_path(C, major, A, minor);
+        assert_eq!(
+            path,
+            vec![
+                (B, ChordFormula::diminished_triad()),
+                (E, ChordFormula::dominant_seventh()),
+                (A, ChordFormula::minor_triad()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_modulation_path_direct() {
+        // C major and F# major share no triad
+        let major = ScaleFormula::major();
+        let path = modulation_path(C, major, F_SHARP, major);
+        assert_eq!(
+            path,
+            vec![
+                (C_SHARP, ChordFormula::dominant_seventh()),
+                (F_SHARP, ChordFormula::major_triad()),
+            ]
+        );
+
+        // No tonic triad in the target scale
+        let pentatonic = ScaleFormula::pentatonic_major();
+        assert!(modulation_path(C, major, D, pentatonic).is_empty());
+    }
+}
@@ -0,0 +1,219 @@
+//! Helpers for instrument-specific transposition.
+//!
+//! This module provides utilities that translate between what a player reads
+//! or fingers and the pitch that actually sounds, such as guitar capo positions
+//! and the `TransposingInstrument` enum for band and orchestral instruments.
+
+use crate::pitch_class::PitchClass;
+use crate::semitone::SEMITONES_IN_OCTAVE;
+
+/// Returns the key that sounds when chord shapes in `open_key` are played with a capo.
+///
+/// Each fret raises the pitch by one semitone.
+///
+/// # Examples
+/// ```
+/// use musik_std::{capo_key, C, D};
+///
+/// assert_eq!(capo_key(C, 2), D); // C shapes, capo 2, sound in D
+/// ```
+pub const fn capo_key(open_key: PitchClass, capo_fret: u8) -> PitchClass {
+    PitchClass::new(open_key.value() + capo_fret % SEMITONES_IN_OCTAVE)
+}
+
+/// Returns the chord shape to play with a capo so that the music sounds in `sounding_key`.
+///
+/// This is the inverse of [`capo_key`].
+///
+/// # Examples
+/// ```
+/// use musik_std::{shape_for, C, D};
+///
+/// assert_eq!(shape_for(D, 2), C); // To sound in D with capo 2, play C shapes
+/// ```
+pub const fn shape_for(sounding_key: PitchClass, capo_fret: u8) -> PitchClass {
+    PitchClass::new(sounding_key.value() + SEMITONES_IN_OCTAVE - capo_fret % SEMITONES_IN_OCTAVE)
+}
+
+/// An instrument whose written part differs from the concert pitch it sounds.
+///
+/// Each instrument is named after its key: the concert pitch that sounds when
+/// the player reads a written C.
+///
+/// # Examples
+/// ```
+/// use musik_std::{TransposingInstrument, B_FLAT, C, D};
+///
+/// let trumpet = TransposingInstrument::BbTrumpet;
+/// assert_eq!(trumpet.written_to_concert(C), B_FLAT);
+/// assert_eq!(trumpet.concert_to_written(C), D);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TransposingInstrument {
+    /// Non-transposing instrument (piano, flute, violin, ...)
+    Concert,
+    /// B♭ clarinet, sounds a major 2nd below written
+    BbClarinet,
+    /// B♭ trumpet, sounds a major 2nd below written
+    BbTrumpet,
+    /// B♭ tenor saxophone, sounds a major 9th below written
+    BbTenorSax,
+    /// E♭ alto saxophone, sounds a major 6th below written
+    EbAltoSax,
+    /// E♭ baritone saxophone, sounds an octave and a major 6th below written
+    EbBaritoneSax,
+    /// A clarinet, sounds a minor 3rd below written
+    AClarinet,
+    /// F horn, sounds a perfect 5th below written
+    FHorn,
+    /// English horn (in F), sounds a perfect 5th below written
+    EnglishHorn,
+}
+
+impl TransposingInstrument {
+    /// Get the concert pitch class that sounds when the instrument reads a written C.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{TransposingInstrument, C, E_FLAT};
+    ///
+    /// assert_eq!(TransposingInstrument::EbAltoSax.key(), E_FLAT);
+    /// assert_eq!(TransposingInstrument::Concert.key(), C);
+    /// ```
+    pub const fn key(&self) -> PitchClass {
+        match self {
+            TransposingInstrument::Concert => PitchClass::new(0),
+            TransposingInstrument::BbClarinet
+            | TransposingInstrument::BbTrumpet
+            | TransposingInstrument::BbTenorSax => PitchClass::new(10),
+            TransposingInstrument::EbAltoSax | TransposingInstrument::EbBaritoneSax => {
+                PitchClass::new(3)
+            }
+            TransposingInstrument::AClarinet => PitchClass::new(9),
+            TransposingInstrument::FHorn | TransposingInstrument::EnglishHorn => PitchClass::new(5),
+        }
+    }
+
+    /// Convert a written pitch class to the concert pitch class that sounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{TransposingInstrument, A, E, G};
+    ///
+    /// assert_eq!(TransposingInstrument::EbAltoSax.written_to_concert(E), G);
+    /// assert_eq!(TransposingInstrument::FHorn.written_to_concert(E), A);
+    /// ```
+    pub const fn written_to_concert(&self, note: PitchClass) -> PitchClass {
+        PitchClass::new(note.value() + self.key().value())
+    }
+
+    /// Convert a concert pitch class to the pitch class written for this instrument.
+    ///
+    /// This is the inverse of [`written_to_concert`](Self::written_to_concert).
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{TransposingInstrument, C, G};
+    ///
+    /// assert_eq!(TransposingInstrument::FHorn.concert_to_written(C), G);
+    /// ```
+    pub const fn concert_to_written(&self, note: PitchClass) -> PitchClass {
+        PitchClass::new(note.value() + SEMITONES_IN_OCTAVE - self.key().value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pitch_class::{A, B, B_FLAT, C, D, E, E_FLAT, F, F_SHARP, G};
+
+    const INSTRUMENTS: [TransposingInstrument; 9] = [
+        TransposingInstrument::Concert,
+        TransposingInstrument::BbClarinet,
+        TransposingInstrument::BbTrumpet,
+        TransposingInstrument::BbTenorSax,
+        TransposingInstrument::EbAltoSax,
+        TransposingInstrument::EbBaritoneSax,
+        TransposingInstrument::AClarinet,
+        TransposingInstrument::FHorn,
+        TransposingInstrument::EnglishHorn,
+    ];
+
+    #[test]
+    fn test_capo_key() {
+        assert_eq!(capo_key(C, 2), D);
+        assert_eq!(capo_key(G, 4), B);
+        assert_eq!(capo_key(E, 0), E);
+
+        // A capo past the 12th fret wraps around the octave
+        assert_eq!(capo_key(A, 12), A);
+        assert_eq!(capo_key(A, 14), B);
+        assert_eq!(capo_key(A, u8::MAX), capo_key(A, u8::MAX % 12));
+    }
+
+    #[test]
+    fn test_shape_for() {
+        assert_eq!(shape_for(D, 2), C);
+        assert_eq!(shape_for(B, 4), G);
+        assert_eq!(shape_for(C, 3), A);
+        assert_eq!(shape_for(E, 0), E);
+    }
+
+    #[test]
+    fn test_capo_roundtrip() {
+        for key in PitchClass::all() {
+            for fret in 0..=24 {
+                assert_eq!(shape_for(capo_key(key, fret), fret), key);
+                assert_eq!(capo_key(shape_for(key, fret), fret), key);
+            }
+        }
+    }
+
+    #[test]
+    fn test_transposing_instrument_key() {
+        assert_eq!(TransposingInstrument::Concert.key(), C);
+        assert_eq!(TransposingInstrument::BbClarinet.key(), B_FLAT);
+        assert_eq!(TransposingInstrument::BbTenorSax.key(), B_FLAT);
+        assert_eq!(TransposingInstrument::EbBaritoneSax.key(), E_FLAT);
+        assert_eq!(TransposingInstrument::AClarinet.key(), A);
+        assert_eq!(TransposingInstrument::EnglishHorn.key(), F);
+
+        // The key is the sounding pitch of a written C
+        for instrument in INSTRUMENTS {
+            assert_eq!(instrument.written_to_concert(C), instrument.key());
+        }
+    }
+
+    #[test]
+    fn test_written_to_concert() {
+        let trumpet = TransposingInstrument::BbTrumpet;
+        assert_eq!(trumpet.written_to_concert(C), B_FLAT);
+        assert_eq!(trumpet.written_to_concert(D), C);
+        assert_eq!(TransposingInstrument::EbAltoSax.written_to_concert(A), C);
+        assert_eq!(TransposingInstrument::FHorn.written_to_concert(G), C);
+        assert_eq!(
+            TransposingInstrument::Concert.written_to_concert(F_SHARP),
+            F_SHARP
+        );
+    }
+
+    #[test]
+    fn test_concert_to_written() {
+        let trumpet = TransposingInstrument::BbTrumpet;
+        assert_eq!(trumpet.concert_to_written(C), D);
+        assert_eq!(trumpet.concert_to_written(B_FLAT), C);
+        assert_eq!(TransposingInstrument::EbAltoSax.concert_to_written(C), A);
+        assert_eq!(
+            TransposingInstrument::AClarinet.concert_to_written(C),
+            E_FLAT
+        );
+        assert_eq!(TransposingInstrument::FHorn.concert_to_written(E), B);
+
+        for instrument in INSTRUMENTS {
+            for pitch_class in PitchClass::all() {
+                let written = instrument.concert_to_written(pitch_class);
+                assert_eq!(instrument.written_to_concert(written), pitch_class);
+            }
+        }
+    }
+}
@@ -12,11 +12,27 @@
 //! - **Semitone**: Basic musical interval representation
 //! - **Note**: Musical note abstraction containing semitone information
 //! - **Octave**: Musical octave position representation
+//! - **MicroPitch**: Cent-resolution pitches for quarter tones and other microtonal music
 //! - **PitchClass**: Representation of the 12 chromatic pitch classes (C, C#, D, etc.)
 //! - **DegreeAlteration**: Musical alterations (sharp/flat) for degree modifications
+//! - **DirectedInterval**: Ascending or descending intervals for transposing notes
 //! - **FormulaDegree**: Extended harmony degrees for chord construction (9ths, 11ths, 13ths, alterations)
 //! - **ChordFormula**: Bit-packed chord degree representation for efficient chord storage and analysis
+//! - **ChordDescriptor**: Plain-data form of a chord formula for storage and serialization
+//! - **ChordQuality**: Major, minor, diminished, and augmented triad qualities
+//! - **NoteRole**: Chord tone, tension, and avoid-note classification of melody notes
 //! - **ScaleFormula**: Bit-flag representation of scale patterns and formulas
+//! - **Tendency**: Where active scale degrees resolve in classical voice leading
+//! - **Harmony**: Reharmonization helpers such as tritone substitution and resolution, progression transposition, related keys, modulation paths, voicing metrics, and parallel fifth/octave checks
+//! - **Instruments**: Guitar capo positions and transposing instruments (B♭, E♭, F)
+//! - **Melody**: Contour and interval analysis, retrograde, and inversion of note sequences
+//! - **MelodyGenerator**: First-order Markov model for generating melodies over pitch classes
+//! - **MIDI**: Tick-based timing helpers such as quantization, swing, humanization, and progression comping, and raw data conversion
+//! - **Notation**: Text notation such as ABC note strings and chord symbol and progression parsing
+//! - **Analysis**: Chord, scale, and key recognition from collections of pitch classes, and chroma vectors
+//! - **Audio**: Frequency helpers such as cent-based interval comparison
+//! - **Transpose**: Transposition of notes and semitones with an explicit wrap, saturate, or checked overflow policy
+//! - **Tuning**: Equal temperament, just intonation, and Pythagorean tuning systems
 //! - **Prelude**: Convenient imports for commonly used types and traits
 //!
 //! Future features may include:
@@ -26,6 +42,10 @@
 //! - **Common Patterns**: Builder patterns, iterators, and convenience functions
 //! - **Integration Helpers**: Bridge functions between different music representations
 //!
+//! ## Optional Features
+//!
+//! - `rand`: Random note selection within scales and melody generation, built on the `rand` crate
+//!
 //! ## Usage
 //!
 //! ### Using the prelude (recommended)
@@ -100,21 +120,58 @@
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
 // Module declarations
+mod analysis;
+mod audio;
+mod chord_descriptor;
 mod chord_formula;
+mod chord_quality;
 mod degree_alteration;
+mod directed_interval;
 mod formula_degree;
+mod harmony;
+mod instruments;
+mod melody;
+mod melody_generator;
+mod micro_pitch;
+mod midi;
+mod notation;
 mod note;
+mod note_role;
 mod octave;
 mod pitch_class;
 pub mod prelude;
 mod scale_formula;
 mod semitone;
+mod tendency;
+mod transpose;
+mod tuning;
 
 // Re-exports
+pub use analysis::{analyze, chroma_vector, detect_key, normalize_chroma, AnalysisResult};
+pub use audio::{approx_equal_cents, cents_between, A4_FREQUENCY, CENTS_IN_OCTAVE};
+pub use chord_descriptor::ChordDescriptor;
 pub use chord_formula::ChordFormula;
+pub use chord_quality::ChordQuality;
 pub use degree_alteration::DegreeAlteration;
+pub use directed_interval::DirectedInterval;
 pub use formula_degree::FormulaDegree;
-pub use note::Note;
+pub use harmony::{
+    apply_tritone_subs, has_parallel_fifths, has_parallel_octaves, modulation_path, related_keys,
+    resolve_tritone, transpose_progression, tritone_sub, voicing_density, voicing_spread,
+};
+pub use instruments::{capo_key, shape_for, TransposingInstrument};
+pub use melody::{
+    contour_string, intervals_between, invert, melody_contour, pitch_class_intervals, retrograde,
+    Contour,
+};
+pub use melody_generator::MelodyGenerator;
+pub use micro_pitch::MicroPitch;
+#[cfg(feature = "rand")]
+pub use midi::humanize;
+pub use midi::{apply_swing, comp_progression, notes_from_bytes, quantize_ticks};
+pub use notation::{parse_chord, parse_progression, to_abc, ParseChordError};
+pub use note::{frequency_table, midi_float_from_frequency, Note};
+pub use note_role::NoteRole;
 pub use octave::Octave;
 pub use pitch_class::{
     PitchClass, A, A_FLAT, A_SHARP, B, B_FLAT, C, C_SHARP, D, D_FLAT, D_SHARP, E, E_FLAT, F,
@@ -122,6 +179,9 @@ pub use pitch_class::{
 };
 pub use scale_formula::ScaleFormula;
 pub use semitone::{Semitone, SEMITONES_IN_OCTAVE};
+pub use tendency::Tendency;
+pub use transpose::{OverflowPolicy, Transpose};
+pub use tuning::{EqualTemperament, JustIntonation, Pythagorean, Tuning};
 
 #[cfg(test)]
 mod tests {
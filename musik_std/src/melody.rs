@@ -0,0 +1,324 @@
+//! Melodic analysis and transformation of note sequences.
+//!
+//! This module provides contour analysis and interval profiles for describing
+//! the shape of a melody, along with the classic retrograde and inversion
+//! transforms.
+
+use crate::note::Note;
+use crate::pitch_class::PitchClass;
+use crate::semitone::SEMITONES_IN_OCTAVE;
+use std::cmp::Ordering;
+use std::fmt;
+
+/// The direction of motion between two consecutive notes.
+///
+/// # Examples
+/// ```
+/// use musik_std::Contour;
+///
+/// assert_eq!(Contour::Up.symbol(), '+');
+/// assert_eq!(Contour::Down.symbol(), '-');
+/// assert_eq!(Contour::Same.symbol(), '=');
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Contour {
+    /// The next note is higher
+    Up,
+    /// The next note is lower
+    Down,
+    /// The next note is repeated
+    Same,
+}
+
+impl Contour {
+    /// Get the contour between two notes.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{Contour, Note};
+    ///
+    /// assert_eq!(Contour::between(Note::new(60), Note::new(64)), Contour::Up);
+    /// assert_eq!(Contour::between(Note::new(64), Note::new(60)), Contour::Down);
+    /// assert_eq!(Contour::between(Note::new(60), Note::new(60)), Contour::Same);
+    /// ```
+    pub fn between(from: Note, to: Note) -> Self {
+        match to.cmp(&from) {
+            Ordering::Greater => Contour::Up,
+            Ordering::Less => Contour::Down,
+            Ordering::Equal => Contour::Same,
+        }
+    }
+
+    /// Get the compact symbol for the contour (`+`, `-`, or `=`).
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::Contour;
+    ///
+    /// assert_eq!(Contour::Up.symbol(), '+');
+    /// ```
+    pub const fn symbol(&self) -> char {
+        match self {
+            Contour::Up => '+',
+            Contour::Down => '-',
+            Contour::Same => '=',
+        }
+    }
+}
+
+impl fmt::Display for Contour {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
+/// Describe the direction of motion between each pair of consecutive notes.
+///
+/// The result has one entry fewer than the input; melodies with fewer than two
+/// notes have an empty contour.
+///
+/// # Examples
+/// ```
+/// use musik_std::{melody_contour, Contour, Note};
+///
+/// let melody = [Note::new(60), Note::new(64), Note::new(62), Note::new(62)];
+/// assert_eq!(
+///     melody_contour(&melody),
+///     vec![Contour::Up, Contour::Down, Contour::Same]
+/// );
+/// ```
+pub fn melody_contour(notes: &[Note]) -> Vec<Contour> {
+    notes
+        .windows(2)
+        .map(|pair| Contour::between(pair[0], pair[1]))
+        .collect()
+}
+
+/// Describe a melody's contour as a compact string such as `"+-+="`.
+///
+/// # Examples
+/// ```
+/// use musik_std::{contour_string, Note};
+///
+/// let melody = [Note::new(60), Note::new(64), Note::new(62), Note::new(67), Note::new(67)];
+/// assert_eq!(contour_string(&melody), "+-+=");
+/// ```
+pub fn contour_string(notes: &[Note]) -> String {
+    melody_contour(notes).iter().map(Contour::symbol).collect()
+}
+
+/// Get the signed semitone difference between each pair of consecutive notes.
+///
+/// Positive values move up and negative values move down. The result has one
+/// entry fewer than the input.
+///
+/// # Examples
+/// ```
+/// use musik_std::{intervals_between, Note};
+///
+/// let melody = [Note::new(60), Note::new(67), Note::new(64), Note::new(64)];
+/// assert_eq!(intervals_between(&melody), vec![7, -3, 0]);
+/// ```
+pub fn intervals_between(notes: &[Note]) -> Vec<i16> {
+    notes
+        .windows(2)
+        .map(|pair| pair[1].semitone() as i16 - pair[0].semitone() as i16)
+        .collect()
+}
+
+/// Get the ascending interval (0-11) between each pair of consecutive pitch classes.
+///
+/// This is the octave-free counterpart of [`intervals_between`]: every step is
+/// measured upwards to the next pitch class.
+///
+/// # Examples
+/// ```
+/// use musik_std::{pitch_class_intervals, A, B, C, D, E, F, G};
+///
+/// let scale = [C, D, E, F, G, A, B, C];
+/// assert_eq!(pitch_class_intervals(&scale), vec![2, 2, 1, 2, 2, 2, 1]);
+/// ```
+pub fn pitch_class_intervals(pitch_classes: &[PitchClass]) -> Vec<u8> {
+    pitch_classes
+        .windows(2)
+        .map(|pair| (pair[1].value() + SEMITONES_IN_OCTAVE - pair[0].value()) % SEMITONES_IN_OCTAVE)
+        .collect()
+}
+
+/// Reverse the order of a melody.
+///
+/// # Examples
+/// ```
+/// use musik_std::{retrograde, Note};
+///
+/// let melody = [Note::new(60), Note::new(64), Note::new(67)];
+/// assert_eq!(retrograde(&melody), vec![Note::new(67), Note::new(64), Note::new(60)]);
+/// ```
+pub fn retrograde(notes: &[Note]) -> Vec<Note> {
+    notes.iter().rev().copied().collect()
+}
+
+/// Reflect each note of a melody around an axis pitch.
+///
+/// A note `n` semitones above the axis becomes `n` semitones below it, and vice
+/// versa. Results beyond the note range saturate at its bounds.
+///
+/// # Examples
+/// ```
+/// use musik_std::{invert, Note};
+///
+/// let c4 = Note::new(60);
+/// let melody = [c4, Note::new(64), Note::new(67)]; // C4, E4, G4
+/// assert_eq!(
+///     invert(&melody, c4),
+///     vec![c4, Note::new(56), Note::new(53)] // C4, G♯3, F3
+/// );
+/// ```
+pub fn invert(notes: &[Note], axis: Note) -> Vec<Note> {
+    let axis = axis.semitone() as i16;
+    notes
+        .iter()
+        .map(|note| {
+            let inverted = 2 * axis - note.semitone() as i16;
+            Note::new(inverted.clamp(0, u8::MAX as i16) as u8)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contour_between() {
+        assert_eq!(Contour::between(Note::new(0), Note::new(1)), Contour::Up);
+        assert_eq!(Contour::between(Note::new(1), Note::new(0)), Contour::Down);
+        assert_eq!(Contour::between(Note::new(5), Note::new(5)), Contour::Same);
+
+        // Octave leaps are still directional
+        assert_eq!(Contour::between(Note::new(60), Note::new(72)), Contour::Up);
+        assert_eq!(
+            Contour::between(Note::new(72), Note::new(60)),
+            Contour::Down
+        );
+    }
+
+    #[test]
+    fn test_contour_display() {
+        assert_eq!(format!("{}", Contour::Up), "+");
+        assert_eq!(format!("{}", Contour::Down), "-");
+        assert_eq!(format!("{}", Contour::Same), "=");
+    }
+
+    #[test]
+    fn test_melody_contour() {
+        let ascending: Vec<Note> = [60, 62, 64, 65, 67].into_iter().map(Note::new).collect();
+        assert_eq!(melody_contour(&ascending), vec![Contour::Up; 4]);
+
+        let descending: Vec<Note> = ascending.iter().rev().copied().collect();
+        assert_eq!(melody_contour(&descending), vec![Contour::Down; 4]);
+
+        let repeated = [Note::new(60), Note::new(60)];
+        assert_eq!(melody_contour(&repeated), vec![Contour::Same]);
+
+        assert!(melody_contour(&[]).is_empty());
+        assert!(melody_contour(&[Note::new(60)]).is_empty());
+    }
+
+    #[test]
+    fn test_contour_string() {
+        let melody: Vec<Note> = [60, 64, 62, 67, 67].into_iter().map(Note::new).collect();
+        assert_eq!(contour_string(&melody), "+-+=");
+
+        let ascending: Vec<Note> = [60, 62, 64].into_iter().map(Note::new).collect();
+        assert_eq!(contour_string(&ascending), "++");
+
+        assert_eq!(contour_string(&[]), "");
+        assert_eq!(contour_string(&[Note::new(60)]), "");
+    }
+
+    #[test]
+    fn test_intervals_between() {
+        let scale: Vec<Note> = [60, 62, 64, 65, 67, 69, 71, 72]
+            .into_iter()
+            .map(Note::new)
+            .collect();
+        assert_eq!(intervals_between(&scale), vec![2, 2, 1, 2, 2, 2, 1]);
+
+        let descending: Vec<Note> = scale.iter().rev().copied().collect();
+        assert_eq!(
+            intervals_between(&descending),
+            vec![-1, -2, -2, -2, -1, -2, -2]
+        );
+
+        // Large leaps keep their full size
+        assert_eq!(
+            intervals_between(&[Note::new(0), Note::new(127), Note::new(0)]),
+            vec![127, -127]
+        );
+
+        assert!(intervals_between(&[]).is_empty());
+        assert!(intervals_between(&[Note::new(60)]).is_empty());
+    }
+
+    #[test]
+    fn test_pitch_class_intervals() {
+        use crate::pitch_class::{A, B, C, D, E, F, G};
+
+        assert_eq!(
+            pitch_class_intervals(&[C, D, E, F, G, A, B, C]),
+            vec![2, 2, 1, 2, 2, 2, 1]
+        );
+
+        // Descending motion is measured upwards
+        assert_eq!(pitch_class_intervals(&[C, B]), vec![11]);
+        assert_eq!(pitch_class_intervals(&[G, C]), vec![5]);
+        assert_eq!(pitch_class_intervals(&[E, E]), vec![0]);
+
+        assert!(pitch_class_intervals(&[]).is_empty());
+        assert!(pitch_class_intervals(&[C]).is_empty());
+    }
+
+    #[test]
+    fn test_retrograde() {
+        let melody: Vec<Note> = [60, 64, 62, 67].into_iter().map(Note::new).collect();
+        let reversed = retrograde(&melody);
+        assert_eq!(
+            reversed,
+            [67, 62, 64, 60]
+                .into_iter()
+                .map(Note::new)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(retrograde(&reversed), melody);
+
+        // Retrograde flips the contour
+        assert_eq!(contour_string(&melody), "+-+");
+        assert_eq!(contour_string(&reversed), "-+-");
+
+        assert!(retrograde(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_invert() {
+        let c4 = Note::new(60);
+        let triad = [c4, Note::new(64), Note::new(67)];
+        assert_eq!(invert(&triad, c4), vec![c4, Note::new(56), Note::new(53)]);
+
+        // Inverting twice around the same axis restores the melody
+        assert_eq!(invert(&invert(&triad, c4), c4), triad.to_vec());
+
+        // Inversion negates the interval profile
+        let melody: Vec<Note> = [62, 65, 64, 69].into_iter().map(Note::new).collect();
+        let inverted = invert(&melody, Note::new(64));
+        let negated: Vec<i16> = intervals_between(&melody).iter().map(|i| -i).collect();
+        assert_eq!(intervals_between(&inverted), negated);
+
+        // Saturates at the note range bounds
+        assert_eq!(invert(&[Note::new(20)], Note::new(5)), vec![Note::new(0)]);
+        assert_eq!(
+            invert(&[Note::new(0)], Note::new(200)),
+            vec![Note::new(255)]
+        );
+    }
+}
@@ -0,0 +1,201 @@
+//! Markov-chain melody generation over pitch classes.
+//!
+//! This module provides the `MelodyGenerator` type, a first-order Markov model
+//! trained on example melodies.
+
+use crate::pitch_class::PitchClass;
+use crate::semitone::SEMITONES_IN_OCTAVE;
+
+#[cfg(feature = "rand")]
+use rand::Rng;
+
+/// A first-order Markov model of melodic motion between pitch classes.
+///
+/// Training counts how often each pitch class moves to each other pitch class;
+/// generation then walks those transitions, choosing each next pitch class with
+/// probability proportional to its count.
+///
+/// # Examples
+/// ```
+/// use musik_std::{MelodyGenerator, C, D, E};
+///
+/// let mut generator = MelodyGenerator::new();
+/// generator.train(&[C, D, E, D, C]);
+///
+/// assert_eq!(generator.transition_count(C, D), 1);
+/// assert_eq!(generator.transition_count(D, E), 1);
+/// assert_eq!(generator.transition_count(D, C), 1);
+/// assert_eq!(generator.transition_count(E, C), 0);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MelodyGenerator {
+    transitions: [[u32; SEMITONES_IN_OCTAVE as usize]; SEMITONES_IN_OCTAVE as usize],
+}
+
+impl MelodyGenerator {
+    /// Creates an untrained generator.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{MelodyGenerator, C};
+    ///
+    /// let generator = MelodyGenerator::new();
+    /// assert_eq!(generator.transition_count(C, C), 0);
+    /// ```
+    pub const fn new() -> Self {
+        Self {
+            transitions: [[0; SEMITONES_IN_OCTAVE as usize]; SEMITONES_IN_OCTAVE as usize],
+        }
+    }
+
+    /// Adds the transitions between consecutive pitch classes of a melody to the model.
+    ///
+    /// Training is cumulative: each call adds to the counts of earlier calls.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{MelodyGenerator, C, G};
+    ///
+    /// let mut generator = MelodyGenerator::new();
+    /// generator.train(&[C, G]);
+    /// generator.train(&[C, G, C]);
+    ///
+    /// assert_eq!(generator.transition_count(C, G), 2);
+    /// assert_eq!(generator.transition_count(G, C), 1);
+    /// ```
+    pub fn train(&mut self, melody: &[PitchClass]) {
+        for pair in melody.windows(2) {
+            let count = &mut self.transitions[pair[0].value() as usize][pair[1].value() as usize];
+            *count = count.saturating_add(1);
+        }
+    }
+
+    /// Returns how many times `from` was followed by `to` in the training data.
+    pub const fn transition_count(&self, from: PitchClass, to: PitchClass) -> u32 {
+        self.transitions[from.value() as usize][to.value() as usize]
+    }
+
+    /// Generates a melody of up to `len` pitch classes beginning with `start`.
+    ///
+    /// Each next pitch class is drawn from the trained transitions of the current
+    /// one. Generation stops early if it reaches a pitch class that was never
+    /// followed by anything in training. Requires the `rand` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{MelodyGenerator, C, E, G};
+    ///
+    /// let mut generator = MelodyGenerator::new();
+    /// generator.train(&[C, E, G, C]);
+    ///
+    /// let melody = generator.generate(C, 7, &mut rand::thread_rng());
+    /// assert_eq!(melody, vec![C, E, G, C, E, G, C]);
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn generate(&self, start: PitchClass, len: usize, rng: &mut impl Rng) -> Vec<PitchClass> {
+        let mut melody = Vec::with_capacity(len);
+        if len == 0 {
+            return melody;
+        }
+
+        let mut current = start;
+        melody.push(current);
+        while melody.len() < len {
+            let row = &self.transitions[current.value() as usize];
+            let total: u64 = row.iter().map(|&count| count as u64).sum();
+            if total == 0 {
+                break;
+            }
+
+            let mut choice = rng.gen_range(0..total);
+            let next = row
+                .iter()
+                .position(|&count| {
+                    if choice < count as u64 {
+                        true
+                    } else {
+                        choice -= count as u64;
+                        false
+                    }
+                })
+                .expect("choice is below the row total");
+
+            current = PitchClass::new(next as u8);
+            melody.push(current);
+        }
+
+        melody
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pitch_class::{C, D, E, G};
+
+    #[test]
+    fn test_train_counts_transitions() {
+        let mut generator = MelodyGenerator::new();
+        generator.train(&[C, D, E, C, D, E]);
+
+        assert_eq!(generator.transition_count(C, D), 2);
+        assert_eq!(generator.transition_count(D, E), 2);
+        assert_eq!(generator.transition_count(E, C), 1);
+        assert_eq!(generator.transition_count(C, E), 0);
+
+        // Training is cumulative
+        generator.train(&[C, E]);
+        assert_eq!(generator.transition_count(C, E), 1);
+        assert_eq!(generator.transition_count(C, D), 2);
+    }
+
+    #[test]
+    fn test_train_short_melodies() {
+        let mut generator = MelodyGenerator::new();
+        generator.train(&[]);
+        generator.train(&[C]);
+        assert_eq!(generator, MelodyGenerator::default());
+
+        // Repeated notes are self-transitions
+        generator.train(&[G, G, G]);
+        assert_eq!(generator.transition_count(G, G), 2);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_generate_reproduces_pattern() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let pattern = [C, E, G, E, C, E, G, E, C];
+        let mut generator = MelodyGenerator::new();
+        generator.train(&pattern);
+
+        let mut rng = StdRng::seed_from_u64(3);
+        let melody = generator.generate(C, 32, &mut rng);
+        assert_eq!(melody.len(), 32);
+        assert_eq!(melody[0], C);
+
+        // Every generated step is a transition seen in training
+        for pair in melody.windows(2) {
+            assert!(generator.transition_count(pair[0], pair[1]) > 0);
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_generate_edge_cases() {
+        use crate::pitch_class::{A, F};
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut generator = MelodyGenerator::new();
+        generator.train(&[D, F, A]);
+
+        assert!(generator.generate(D, 0, &mut rng).is_empty());
+        assert_eq!(generator.generate(D, 1, &mut rng), vec![D]);
+
+        // Stops at a pitch class with no outgoing transitions
+        assert_eq!(generator.generate(D, 10, &mut rng), vec![D, F, A]);
+        assert_eq!(generator.generate(C, 10, &mut rng), vec![C]);
+    }
+}
@@ -0,0 +1,194 @@
+//! Microtonal pitch representation.
+//!
+//! This module provides the `MicroPitch` type, which measures pitch in cents
+//! rather than semitones, for quarter tones, 24-TET, and other tunings that
+//! fall between the keys of the piano.
+
+use crate::audio::CENTS_IN_OCTAVE;
+use crate::note::{Note, A4_MIDI};
+use crate::semitone::Semitone;
+use std::ops::{Add, Sub};
+
+/// The number of cents in an equal-tempered semitone.
+const CENTS_IN_SEMITONE: i32 = 100;
+
+/// Highest valid MIDI note number.
+const MAX_MIDI_NOTE: i32 = 127;
+
+/// A pitch measured in cents above MIDI note 0 (C-1).
+///
+/// Every equal-tempered note sits on a multiple of 100 cents; values in
+/// between describe microtonal pitches such as quarter tones.
+///
+/// # Examples
+/// ```
+/// use musik_std::{MicroPitch, Note};
+///
+/// // A quarter tone above A4
+/// let pitch = MicroPitch::from(Note::new(69)) + 50;
+/// assert_eq!(pitch.cents(), 6950);
+/// assert_eq!(pitch.nearest_note(), (Note::new(69), 50.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MicroPitch(i32);
+
+impl MicroPitch {
+    /// Creates a pitch the given number of cents above MIDI note 0.
+    pub const fn new(cents: i32) -> Self {
+        Self(cents)
+    }
+
+    /// Returns the number of cents above MIDI note 0.
+    pub const fn cents(self) -> i32 {
+        self.0
+    }
+
+    /// Returns the frequency of this pitch in Hz for a given A4 reference.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{MicroPitch, Note};
+    ///
+    /// let a4 = MicroPitch::from(Note::new(69));
+    /// assert_eq!(a4.frequency(440.0), 440.0);
+    /// assert_eq!((a4 + 1200).frequency(440.0), 880.0);
+    /// ```
+    pub fn frequency(&self, a4: f64) -> f64 {
+        let offset = self.0.saturating_sub(A4_MIDI as i32 * CENTS_IN_SEMITONE);
+        a4 * 2f64.powf(offset as f64 / CENTS_IN_OCTAVE)
+    }
+
+    /// Returns the closest MIDI note and the deviation from it in cents.
+    ///
+    /// A pitch exactly halfway between two notes reports the lower note with
+    /// a deviation of +50 cents. Pitches outside the MIDI range (0-127) report
+    /// the nearest end of the range.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{MicroPitch, Note};
+    ///
+    /// assert_eq!(MicroPitch::new(6980).nearest_note(), (Note::new(70), -20.0));
+    /// assert_eq!(MicroPitch::new(6920).nearest_note(), (Note::new(69), 20.0));
+    /// ```
+    pub fn nearest_note(&self) -> (Note, f64) {
+        let half = CENTS_IN_SEMITONE / 2;
+        let midi = self
+            .0
+            .saturating_add(half - 1)
+            .div_euclid(CENTS_IN_SEMITONE)
+            .clamp(0, MAX_MIDI_NOTE);
+        let deviation = self.0 - midi * CENTS_IN_SEMITONE;
+        (Note::new(midi as u8), deviation as f64)
+    }
+}
+
+impl Add<i32> for MicroPitch {
+    type Output = MicroPitch;
+
+    /// Raises the pitch by the given number of cents, saturating at the bounds of `i32`.
+    fn add(self, cents: i32) -> Self::Output {
+        MicroPitch(self.0.saturating_add(cents))
+    }
+}
+
+impl Sub<i32> for MicroPitch {
+    type Output = MicroPitch;
+
+    /// Lowers the pitch by the given number of cents, saturating at the bounds of `i32`.
+    fn sub(self, cents: i32) -> Self::Output {
+        MicroPitch(self.0.saturating_sub(cents))
+    }
+}
+
+impl From<Semitone> for MicroPitch {
+    /// Creates the equal-tempered pitch of a semitone value.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{MicroPitch, Semitone};
+    ///
+    /// assert_eq!(MicroPitch::from(Semitone::new(60)).cents(), 6000);
+    /// ```
+    fn from(semitone: Semitone) -> Self {
+        MicroPitch(u8::from(semitone) as i32 * CENTS_IN_SEMITONE)
+    }
+}
+
+impl From<Note> for MicroPitch {
+    /// Creates the equal-tempered pitch of a note.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{MicroPitch, Note};
+    ///
+    /// assert_eq!(MicroPitch::from(Note::new(69)).cents(), 6900);
+    /// ```
+    fn from(note: Note) -> Self {
+        MicroPitch::from(note.as_semitone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frequency_matches_notes() {
+        for midi in 0..=127u8 {
+            let note = Note::new(midi);
+            let pitch = MicroPitch::from(note);
+            assert!((pitch.frequency(440.0) - note.frequency()).abs() < 1e-9);
+            assert!((pitch.frequency(415.0) - note.frequency_with_reference(415.0)).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_quarter_tone() {
+        let a4 = MicroPitch::from(Note::new(69));
+        let a_sharp4 = MicroPitch::from(Note::new(70));
+        let quarter_tone = a4 + 50;
+
+        // Halfway between A4 and A#4 on a logarithmic scale
+        let midpoint = (a4.frequency(440.0) * a_sharp4.frequency(440.0)).sqrt();
+        assert!((quarter_tone.frequency(440.0) - midpoint).abs() < 1e-9);
+        assert_eq!(quarter_tone.nearest_note(), (Note::new(69), 50.0));
+
+        // Just past the midpoint rounds up
+        assert_eq!((a4 + 51).nearest_note(), (Note::new(70), -49.0));
+        assert_eq!((a4 - 50).nearest_note(), (Note::new(68), 50.0));
+    }
+
+    #[test]
+    fn test_nearest_note_clamps_to_midi_range() {
+        assert_eq!(MicroPitch::new(-30).nearest_note(), (Note::new(0), -30.0));
+        assert_eq!(
+            MicroPitch::new(12780).nearest_note(),
+            (Note::new(127), 80.0)
+        );
+    }
+
+    #[test]
+    fn test_extreme_pitches_saturate() {
+        let highest = MicroPitch::new(i32::MAX);
+        let lowest = MicroPitch::new(i32::MIN);
+
+        assert_eq!(highest + 1, highest);
+        assert_eq!(highest - i32::MIN, highest);
+        assert_eq!(lowest - 1, lowest);
+        assert_eq!(lowest + i32::MIN, lowest);
+
+        assert_eq!(highest.nearest_note().0, Note::new(127));
+        assert_eq!(lowest.nearest_note().0, Note::new(0));
+        assert!(highest.frequency(440.0).is_infinite());
+        assert_eq!(lowest.frequency(440.0), 0.0);
+    }
+
+    #[test]
+    fn test_from_semitone() {
+        let pitch = MicroPitch::from(Semitone::new(64));
+        assert_eq!(pitch.cents(), 6400);
+        assert_eq!(pitch.nearest_note(), (Note::new(64), 0.0));
+        assert_eq!(pitch - 1200, MicroPitch::new(5200));
+    }
+}
@@ -0,0 +1,416 @@
+//! MIDI timing utilities.
+//!
+//! This module provides helpers for adjusting note timings expressed in MIDI
+//! ticks, such as grid quantization, swing, and humanization, for comping a
+//! chord progression on a tick grid, and for reading notes out of raw MIDI data.
+
+use crate::chord_formula::ChordFormula;
+use crate::note::Note;
+use crate::pitch_class::PitchClass;
+
+#[cfg(feature = "rand")]
+use rand::Rng;
+
+/// Note around which comped chord voicings are centered (middle C).
+const COMP_CENTER: u8 = 60;
+
+/// Lowest Note On velocity that still sounds; 0 means Note Off.
+#[cfg(feature = "rand")]
+const MIN_VELOCITY: u8 = 1;
+
+/// Highest MIDI velocity.
+#[cfg(feature = "rand")]
+const MAX_VELOCITY: u8 = 127;
+
+/// Snaps a tick towards the nearest multiple of `grid`.
+///
+/// `strength` interpolates between the original position (0.0) and the fully
+/// quantized one (1.0), matching the quantize strength found in most DAWs. It is
+/// clamped to that range, and the result is rounded to the nearest tick. Ticks
+/// exactly halfway between two grid lines snap forward. A `grid` of 0 leaves the
+/// tick unchanged.
+///
+/// # Examples
+/// ```
+/// use musik_std::quantize_ticks;
+///
+/// assert_eq!(quantize_ticks(115, 120, 1.0), 120); // Hard snap
+/// assert_eq!(quantize_ticks(115, 120, 0.0), 115); // No change
+/// assert_eq!(quantize_ticks(100, 120, 0.5), 110); // Halfway
+/// ```
+pub fn quantize_ticks(tick: u32, grid: u32, strength: f64) -> u32 {
+    if grid == 0 {
+        return tick;
+    }
+
+    let tick = tick as u64;
+    let grid = grid as u64;
+    let snapped = (tick + grid / 2) / grid * grid;
+
+    let strength = strength.clamp(0.0, 1.0);
+    let moved = tick as f64 + (snapped as f64 - tick as f64) * strength;
+    moved.round().min(u32::MAX as f64) as u32
+}
+
+/// Applies swing to a tick by delaying the off-beat subdivisions.
+///
+/// `grid` is the length of one subdivision (for example an eighth note), so each
+/// pair of subdivisions forms a swing cycle. `swing_ratio` is the fraction of the
+/// cycle given to the first subdivision: 0.5 is straight and 2/3 is triplet swing.
+/// Ticks within a cycle are stretched piecewise-linearly, so on-beats never move
+/// and an off-beat lands at `swing_ratio` of the cycle. The ratio is clamped to
+/// 0.0..=1.0, and a `grid` of 0 leaves the tick unchanged.
+///
+/// # Examples
+/// ```
+/// use musik_std::apply_swing;
+///
+/// // Straight eighths with 240 ticks each
+/// assert_eq!(apply_swing(240, 240, 0.5), 240);
+///
+/// // Triplet swing moves the second eighth to the last triplet
+/// assert_eq!(apply_swing(240, 240, 2.0 / 3.0), 320);
+/// assert_eq!(apply_swing(480, 240, 2.0 / 3.0), 480); // On-beat stays put
+/// ```
+pub fn apply_swing(tick: u32, grid: u32, swing_ratio: f64) -> u32 {
+    if grid == 0 {
+        return tick;
+    }
+
+    let cycle = 2 * grid as u64;
+    let cycle_start = tick as u64 / cycle * cycle;
+    let position = (tick as u64 - cycle_start) as f64;
+
+    let grid = grid as f64;
+    let split = cycle as f64 * swing_ratio.clamp(0.0, 1.0);
+    let swung = if position < grid {
+        position * split / grid
+    } else {
+        split + (position - grid) * (cycle as f64 - split) / grid
+    };
+
+    (cycle_start as f64 + swung).round().min(u32::MAX as f64) as u32
+}
+
+/// Randomly nudges the timing and velocity of note events to sound less mechanical.
+///
+/// Each event is a `(tick, velocity)` pair. Every tick moves by up to
+/// `timing_jitter_ticks` in either direction, saturating at 0 and `u32::MAX`,
+/// and every velocity moves by up to `velocity_jitter` in either direction,
+/// clamped to the audible Note On range 1..=127. Events with velocity 0 are
+/// Note Offs by MIDI convention: their timing is nudged too, but their velocity
+/// stays 0, and each one is kept at least one tick after the last Note On before
+/// it so a note never ends before it starts. Requires the `rand` feature.
+///
+/// # Examples
+/// ```
+/// use musik_std::humanize;
+///
+/// let mut events = [(0, 100), (480, 100), (960, 100)];
+/// humanize(&mut events, 10, 8, &mut rand::thread_rng());
+///
+/// for (index, &(tick, velocity)) in events.iter().enumerate() {
+///     assert!(tick.abs_diff(index as u32 * 480) <= 10);
+///     assert!((92..=108).contains(&velocity));
+/// }
+/// ```
+#[cfg(feature = "rand")]
+pub fn humanize(
+    events: &mut [(u32, u8)],
+    timing_jitter_ticks: u32,
+    velocity_jitter: u8,
+    rng: &mut impl Rng,
+) {
+    let timing_jitter = timing_jitter_ticks as i64;
+    let velocity_jitter = velocity_jitter as i16;
+
+    let mut last_note_on: Option<u32> = None;
+
+    for (tick, velocity) in events.iter_mut() {
+        let nudge = rng.gen_range(-timing_jitter..=timing_jitter);
+        *tick = (*tick as i64 + nudge).clamp(0, u32::MAX as i64) as u32;
+
+        if *velocity == 0 {
+            if let Some(note_on) = last_note_on {
+                *tick = (*tick).max(note_on.saturating_add(1));
+            }
+            continue;
+        }
+
+        let nudge = rng.gen_range(-velocity_jitter..=velocity_jitter);
+        *velocity =
+            (*velocity as i16 + nudge).clamp(MIN_VELOCITY as i16, MAX_VELOCITY as i16) as u8;
+        last_note_on = Some(*tick);
+    }
+}
+
+/// Spreads each chord of a progression across a bar following a rhythmic pattern.
+///
+/// Each `(root, formula)` chord fills one bar of `ticks_per_bar` ticks and is
+/// voiced around middle C with [`ChordFormula::voice_near`]. `pattern` holds the
+/// duration of each hit in ticks; hits are played back to back from the start of
+/// the bar. The last hit is cut at the bar line, and hits that would start at or
+/// after it are dropped. Returns `(start_tick, notes, duration)` events in order.
+///
+/// # Examples
+/// ```
+/// use musik_std::{comp_progression, ChordFormula, Note, C, G};
+///
+/// // C and G in 4/4 at 480 ticks per quarter, two half-note hits per bar
+/// let chords = [(C, ChordFormula::major_triad()), (G, ChordFormula::major_triad())];
+/// let events = comp_progression(&chords, 1920, &[960, 960]);
+///
+/// assert_eq!(events.len(), 4);
+/// assert_eq!(events[0], (0, vec![Note::new(55), Note::new(60), Note::new(64)], 960));
+/// assert_eq!(events[2].0, 1920);
+/// ```
+pub fn comp_progression(
+    chords: &[(PitchClass, ChordFormula)],
+    ticks_per_bar: u32,
+    pattern: &[u32],
+) -> Vec<(u32, Vec<Note>, u32)> {
+    let mut events = Vec::new();
+
+    for (bar, &(root, formula)) in chords.iter().enumerate() {
+        let voicing = formula.voice_near(root, Note::new(COMP_CENTER));
+        let bar_start = bar as u32 * ticks_per_bar;
+        let mut offset = 0;
+
+        for &duration in pattern {
+            if offset >= ticks_per_bar {
+                break;
+            }
+            let duration = duration.min(ticks_per_bar - offset);
+            events.push((bar_start + offset, voicing.clone(), duration));
+            offset += duration;
+        }
+    }
+
+    events
+}
+
+/// Converts the data bytes of a raw MIDI buffer to notes.
+///
+/// Bytes 0-127 are data bytes and become notes; bytes above 127 are status
+/// bytes and are skipped. No attempt is made to interpret the messages, so
+/// velocities and other data bytes are converted too. This is meant for rough
+/// analysis rather than parsing.
+///
+/// # Examples
+/// ```
+/// use musik_std::{notes_from_bytes, Note};
+///
+/// // Note On, channel 1: C4 with velocity 100
+/// let notes = notes_from_bytes(&[0x90, 60, 100]);
+/// assert_eq!(notes, vec![Note::new(60), Note::new(100)]);
+/// ```
+pub fn notes_from_bytes(data: &[u8]) -> Vec<Note> {
+    data.iter()
+        .filter_map(|&byte| Note::new_checked(byte))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_comp_progression() {
+        use crate::pitch_class::{C, G};
+
+        // Quarter-note hits in 4/4 at 480 ticks per quarter
+        let chords = [
+            (C, ChordFormula::major_triad()),
+            (G, ChordFormula::dominant_seventh()),
+        ];
+        let events = comp_progression(&chords, 1920, &[480; 4]);
+
+        assert_eq!(events.len(), 8);
+        let ticks: Vec<u32> = events.iter().map(|(tick, _, _)| *tick).collect();
+        assert_eq!(ticks, vec![0, 480, 960, 1440, 1920, 2400, 2880, 3360]);
+        assert!(events.iter().all(|(_, _, duration)| *duration == 480));
+
+        let c_major = ChordFormula::major_triad().voice_near(C, Note::new(60));
+        let g_seventh = ChordFormula::dominant_seventh().voice_near(G, Note::new(60));
+        assert!(events[..4].iter().all(|(_, notes, _)| *notes == c_major));
+        assert!(events[4..].iter().all(|(_, notes, _)| *notes == g_seventh));
+    }
+
+    #[test]
+    fn test_comp_progression_clips_to_bar() {
+        use crate::pitch_class::C;
+
+        let chords = [(C, ChordFormula::major_triad()); 2];
+
+        // A dotted half and a half: the second hit is cut at the bar line
+        let events = comp_progression(&chords, 1920, &[1440, 960]);
+        let timing: Vec<(u32, u32)> = events
+            .iter()
+            .map(|(tick, _, length)| (*tick, *length))
+            .collect();
+        assert_eq!(
+            timing,
+            vec![(0, 1440), (1440, 480), (1920, 1440), (3360, 480)]
+        );
+
+        // Hits past the bar line are dropped
+        assert_eq!(comp_progression(&chords, 960, &[480; 4]).len(), 4);
+
+        assert!(comp_progression(&[], 1920, &[480; 4]).is_empty());
+        assert!(comp_progression(&chords, 1920, &[]).is_empty());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_humanize() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let original: Vec<(u32, u8)> = (0..200).map(|index| (index * 120, 100)).collect();
+        let mut events = original.clone();
+        humanize(&mut events, 15, 20, &mut StdRng::seed_from_u64(42));
+
+        for (&(tick, velocity), &(original_tick, original_velocity)) in events.iter().zip(&original)
+        {
+            assert!(tick.abs_diff(original_tick) <= 15);
+            assert!(velocity.abs_diff(original_velocity) <= 20);
+        }
+        assert_ne!(events, original);
+
+        // Same seed, same result
+        let mut again = original.clone();
+        humanize(&mut again, 15, 20, &mut StdRng::seed_from_u64(42));
+        assert_eq!(again, events);
+
+        // No jitter, no change
+        let mut still = original.clone();
+        humanize(&mut still, 0, 0, &mut StdRng::seed_from_u64(42));
+        assert_eq!(still, original);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_humanize_clamps() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut events = vec![(0, 1), (5, 127), (u32::MAX, 64)];
+        for _ in 0..100 {
+            humanize(&mut events, 50, 127, &mut rng);
+            for &(_, velocity) in &events {
+                assert!((1..=127).contains(&velocity));
+            }
+        }
+
+        // A Note Off one tick after its Note On, with jitter larger than the gap
+        for seed in 0..100 {
+            let mut events = vec![(1000, 100), (1001, 0)];
+            humanize(&mut events, 50, 127, &mut StdRng::seed_from_u64(seed));
+            let [(note_on, _), (note_off, velocity)] = events[..] else {
+                unreachable!()
+            };
+            assert!(note_on < note_off);
+            assert_eq!(velocity, 0);
+        }
+
+        // Ticks saturate instead of wrapping
+        let mut events = vec![(0, 64); 100];
+        humanize(&mut events, u32::MAX, 0, &mut rng);
+        assert!(events.iter().any(|&(tick, _)| tick == 0));
+
+        let mut events = vec![(u32::MAX, 64); 100];
+        humanize(&mut events, u32::MAX, 0, &mut rng);
+        assert!(events.iter().any(|&(tick, _)| tick == u32::MAX));
+    }
+
+    #[test]
+    fn test_quantize_ticks_strength() {
+        assert_eq!(quantize_ticks(115, 120, 1.0), 120);
+        assert_eq!(quantize_ticks(115, 120, 0.0), 115);
+        assert_eq!(quantize_ticks(115, 120, 0.5), 118); // 117.5 rounds up
+        assert_eq!(quantize_ticks(100, 120, 0.5), 110);
+        assert_eq!(quantize_ticks(100, 120, 0.25), 105);
+
+        // Late notes are pulled back
+        assert_eq!(quantize_ticks(130, 120, 1.0), 120);
+        assert_eq!(quantize_ticks(130, 120, 0.5), 125);
+    }
+
+    #[test]
+    fn test_quantize_ticks_grid() {
+        // Nearest grid line, halfway snaps forward
+        assert_eq!(quantize_ticks(59, 120, 1.0), 0);
+        assert_eq!(quantize_ticks(60, 120, 1.0), 120);
+        assert_eq!(quantize_ticks(240, 120, 1.0), 240);
+        assert_eq!(quantize_ticks(0, 120, 1.0), 0);
+
+        // Zero grid is a no-op
+        assert_eq!(quantize_ticks(115, 0, 1.0), 115);
+    }
+
+    #[test]
+    fn test_quantize_ticks_bounds() {
+        // Strength is clamped
+        assert_eq!(quantize_ticks(115, 120, 2.0), 120);
+        assert_eq!(quantize_ticks(115, 120, -1.0), 115);
+
+        // Snapping past the end of the tick range saturates
+        assert_eq!(quantize_ticks(u32::MAX, 1 << 31, 1.0), u32::MAX);
+        assert_eq!(quantize_ticks(u32::MAX - 1, 1, 1.0), u32::MAX - 1);
+    }
+
+    #[test]
+    fn test_apply_swing_straight() {
+        for tick in (0..2000).step_by(37) {
+            assert_eq!(apply_swing(tick, 240, 0.5), tick);
+        }
+        assert_eq!(apply_swing(115, 0, 0.66), 115);
+    }
+
+    #[test]
+    fn test_apply_swing_offbeats() {
+        // Off-beats are delayed, on-beats stay put
+        assert_eq!(apply_swing(0, 240, 0.66), 0);
+        assert_eq!(apply_swing(240, 240, 0.66), 317); // 480 * 0.66 = 316.8
+        assert_eq!(apply_swing(480, 240, 0.66), 480);
+        assert_eq!(apply_swing(720, 240, 0.66), 797);
+
+        // Triplet swing
+        assert_eq!(apply_swing(240, 240, 2.0 / 3.0), 320);
+        assert_eq!(apply_swing(1200, 240, 2.0 / 3.0), 1280);
+
+        // Ticks between grid lines are stretched proportionally
+        assert_eq!(apply_swing(120, 240, 2.0 / 3.0), 160);
+        assert_eq!(apply_swing(360, 240, 2.0 / 3.0), 400);
+    }
+
+    #[test]
+    fn test_apply_swing_preserves_order() {
+        let swung: Vec<u32> = (0..960).map(|tick| apply_swing(tick, 240, 0.75)).collect();
+        for pair in swung.windows(2) {
+            assert!(pair[0] <= pair[1]);
+        }
+
+        // Ratio is clamped
+        assert_eq!(apply_swing(240, 240, 1.5), apply_swing(240, 240, 1.0));
+        assert_eq!(apply_swing(240, 240, -0.5), apply_swing(240, 240, 0.0));
+    }
+
+    #[test]
+    fn test_notes_from_bytes() {
+        let data = [60, 0x90, 64, 67, 0xFF, 127, 0x80, 0];
+        assert_eq!(
+            notes_from_bytes(&data),
+            vec![
+                Note::new(60),
+                Note::new(64),
+                Note::new(67),
+                Note::new(127),
+                Note::new(0)
+            ]
+        );
+
+        assert!(notes_from_bytes(&[]).is_empty());
+        assert!(notes_from_bytes(&[0x80, 0x90, 0xF8]).is_empty());
+        assert_eq!(notes_from_bytes(&[128, 127]), vec![Note::new(127)]);
+    }
+}
@@ -0,0 +1,343 @@
+//! Text notation for notes and chords.
+//!
+//! This module converts notes to compact text formats understood by existing
+//! engraving tools, such as ABC notation, and parses lead-sheet chord symbols.
+
+use crate::chord_formula::ChordFormula;
+use crate::note::Note;
+use crate::pitch_class::PitchClass;
+use std::fmt;
+
+/// Octave written with uppercase letters and no octave marks in ABC notation.
+const ABC_BASE_OCTAVE: i8 = 4;
+
+/// Error returned by [`parse_progression`] for a chord symbol that does not parse.
+///
+/// # Examples
+/// ```
+/// use musik_std::parse_progression;
+///
+/// let error = parse_progression("Dm7 Xyz G7").unwrap_err();
+/// assert_eq!(error.token, "Xyz");
+/// assert_eq!(error.to_string(), "unrecognized chord symbol `Xyz`");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseChordError {
+    /// The first chord symbol that could not be parsed.
+    pub token: String,
+}
+
+impl fmt::Display for ParseChordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unrecognized chord symbol `{}`", self.token)
+    }
+}
+
+impl std::error::Error for ParseChordError {}
+
+/// Convert notes to an ABC notation fragment, separated by spaces.
+///
+/// Octave 4 (middle C upwards) uses uppercase letters and octave 5 lowercase
+/// letters. Each octave above 5 adds a `'` and each octave below 4 adds a `,`.
+/// Accidentals are spelled as sharps with a `^` prefix.
+///
+/// # Examples
+/// ```
+/// use musik_std::{to_abc, Note};
+///
+/// let c_major: Vec<Note> = [60, 62, 64, 65, 67, 69, 71, 72].map(Note::new).to_vec();
+/// assert_eq!(to_abc(&c_major), "C D E F G A B c");
+///
+/// assert_eq!(to_abc(&[Note::new(66)]), "^F");
+/// assert_eq!(to_abc(&[Note::new(48), Note::new(84)]), "C, c'");
+/// ```
+pub fn to_abc(notes: &[Note]) -> String {
+    notes
+        .iter()
+        .map(|&note| abc_note(note))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Convert a single note to ABC notation.
+fn abc_note(note: Note) -> String {
+    let name = note.pitch_class().name();
+    let (letter, sharp) = name.split_at(1);
+    let octave = note.octave().value();
+
+    let mut abc = String::new();
+    if !sharp.is_empty() {
+        abc.push('^');
+    }
+
+    if octave <= ABC_BASE_OCTAVE {
+        abc.push_str(letter);
+        for _ in octave..ABC_BASE_OCTAVE {
+            abc.push(',');
+        }
+    } else {
+        abc.push_str(&letter.to_lowercase());
+        for _ in ABC_BASE_OCTAVE + 1..octave {
+            abc.push('\'');
+        }
+    }
+
+    abc
+}
+
+/// Parse a lead-sheet chord symbol into its root, formula, and optional slash bass.
+///
+/// The root is a note name as accepted by [`PitchClass::from_name`] and the
+/// rest of the symbol is a suffix as accepted by [`ChordFormula::from_symbol`].
+/// A trailing `/` and note name gives the bass of a slash chord. Returns `None`
+/// if any part does not parse.
+///
+/// # Examples
+/// ```
+/// use musik_std::{parse_chord, ChordFormula, B_FLAT, C, E, F_SHARP};
+///
+/// assert_eq!(parse_chord("Cmaj7"), Some((C, ChordFormula::major_seventh(), None)));
+/// assert_eq!(
+///     parse_chord("F#m7b5"),
+///     Some((F_SHARP, ChordFormula::half_diminished_seventh(), None))
+/// );
+/// assert_eq!(
+///     parse_chord("Bb13"),
+///     Some((B_FLAT, ChordFormula::dominant_thirteenth(), None))
+/// );
+/// assert_eq!(parse_chord("C/E"), Some((C, ChordFormula::major_triad(), Some(E))));
+/// assert_eq!(parse_chord("Xyz"), None);
+/// ```
+pub fn parse_chord(symbol: &str) -> Option<(PitchClass, ChordFormula, Option<PitchClass>)> {
+    let (root, suffix) = split_root(symbol)?;
+
+    // Suffixes such as "6/9" contain a slash themselves
+    if let Some(formula) = ChordFormula::from_symbol(suffix) {
+        return Some((root, formula, None));
+    }
+
+    let (suffix, bass) = suffix.rsplit_once('/')?;
+    Some((
+        root,
+        ChordFormula::from_symbol(suffix)?,
+        Some(PitchClass::from_name(bass)?),
+    ))
+}
+
+/// Parse a chord progression from chord symbols separated by spaces or `|` bar lines.
+///
+/// Each symbol is parsed with [`parse_chord`]. Empty bars are skipped, so an
+/// empty string gives an empty progression.
+///
+/// # Examples
+/// ```
+/// use musik_std::{parse_progression, ChordFormula, C, D, G};
+///
+/// let progression = parse_progression("Dm7 | G7 | Cmaj7").unwrap();
+/// assert_eq!(
+///     progression,
+///     vec![
+///         (D, ChordFormula::minor_seventh(), None),
+///         (G, ChordFormula::dominant_seventh(), None),
+///         (C, ChordFormula::major_seventh(), None),
+///     ]
+/// );
+/// ```
+pub fn parse_progression(
+    progression: &str,
+) -> Result<Vec<(PitchClass, ChordFormula, Option<PitchClass>)>, ParseChordError> {
+    progression
+        .split(|c: char| c.is_whitespace() || c == '|')
+        .filter(|token| !token.is_empty())
+        .map(|token| {
+            parse_chord(token).ok_or_else(|| ParseChordError {
+                token: token.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Split a chord symbol into its root and the remaining suffix.
+///
+/// Prefers a two-character root (letter and accidental) over a bare letter.
+fn split_root(symbol: &str) -> Option<(PitchClass, &str)> {
+    let ends: Vec<usize> = symbol
+        .char_indices()
+        .map(|(index, c)| index + c.len_utf8())
+        .take(2)
+        .collect();
+
+    ends.into_iter().rev().find_map(|end| {
+        let (root, suffix) = symbol.split_at(end);
+        Some((PitchClass::from_name(root)?, suffix))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::note::notes;
+
+    #[test]
+    fn test_to_abc_c_major() {
+        assert_eq!(
+            to_abc(&notes(&[60, 62, 64, 65, 67, 69, 71])),
+            "C D E F G A B"
+        );
+        assert_eq!(
+            to_abc(&notes(&[72, 74, 76, 77, 79, 81, 83])),
+            "c d e f g a b"
+        );
+    }
+
+    #[test]
+    fn test_to_abc_accidentals() {
+        assert_eq!(to_abc(&notes(&[66])), "^F");
+        assert_eq!(to_abc(&notes(&[61, 70, 73])), "^C ^A ^c");
+
+        // G major scale from G4
+        assert_eq!(
+            to_abc(&notes(&[67, 69, 71, 72, 74, 76, 78, 79])),
+            "G A B c d e ^f g"
+        );
+    }
+
+    #[test]
+    fn test_to_abc_octave_marks() {
+        assert_eq!(to_abc(&notes(&[59])), "B,");
+        assert_eq!(to_abc(&notes(&[48])), "C,");
+        assert_eq!(to_abc(&notes(&[36])), "C,,");
+        assert_eq!(to_abc(&notes(&[84])), "c'");
+        assert_eq!(to_abc(&notes(&[97])), "^c''");
+        assert_eq!(to_abc(&notes(&[0])), "C,,,,,");
+    }
+
+    #[test]
+    fn test_to_abc_empty() {
+        assert_eq!(to_abc(&[]), "");
+    }
+
+    #[test]
+    fn test_parse_chord() {
+        use crate::pitch_class::{A, B_FLAT, C, D, E_FLAT, F_SHARP, G};
+
+        assert_eq!(
+            parse_chord("C"),
+            Some((C, ChordFormula::major_triad(), None))
+        );
+        assert_eq!(
+            parse_chord("Dm7"),
+            Some((D, ChordFormula::minor_seventh(), None))
+        );
+        assert_eq!(
+            parse_chord("G7b9"),
+            Some((G, ChordFormula::dominant_seventh_flat_ninth(), None))
+        );
+        assert_eq!(
+            parse_chord("F#m7b5"),
+            Some((F_SHARP, ChordFormula::half_diminished_seventh(), None))
+        );
+        assert_eq!(
+            parse_chord("Bb13"),
+            Some((B_FLAT, ChordFormula::dominant_thirteenth(), None))
+        );
+        assert_eq!(
+            parse_chord("E♭maj9"),
+            Some((E_FLAT, ChordFormula::major_ninth(), None))
+        );
+        assert_eq!(
+            parse_chord("A6/9"),
+            Some((A, ChordFormula::six_nine(), None))
+        );
+
+        // Every catalog suffix parses after a two-character root
+        for (suffix, formula) in ChordFormula::catalog() {
+            assert_eq!(parse_chord(&format!("Ab{suffix}")).unwrap().1, formula);
+        }
+    }
+
+    #[test]
+    fn test_parse_chord_slash() {
+        use crate::pitch_class::{A, B_FLAT, C, D, E, G};
+
+        assert_eq!(
+            parse_chord("C/E"),
+            Some((C, ChordFormula::major_triad(), Some(E)))
+        );
+        assert_eq!(
+            parse_chord("Dm7/G"),
+            Some((D, ChordFormula::minor_seventh(), Some(G)))
+        );
+        assert_eq!(
+            parse_chord("C6/9/Bb"),
+            Some((C, ChordFormula::six_nine(), Some(B_FLAT)))
+        );
+        assert_eq!(
+            parse_chord("Am/C"),
+            Some((A, ChordFormula::minor_triad(), Some(C)))
+        );
+    }
+
+    #[test]
+    fn test_parse_progression() {
+        use crate::pitch_class::{A, C, D, F, G};
+
+        // ii-V-I
+        let two_five_one = parse_progression("Dm7 | G7 | Cmaj7").unwrap();
+        let roots: Vec<PitchClass> = two_five_one.iter().map(|&(root, _, _)| root).collect();
+        let formulas: Vec<ChordFormula> = two_five_one
+            .iter()
+            .map(|&(_, formula, _)| formula)
+            .collect();
+        assert_eq!(roots, vec![D, G, C]);
+        assert_eq!(
+            formulas,
+            vec![
+                ChordFormula::minor_seventh(),
+                ChordFormula::dominant_seventh(),
+                ChordFormula::major_seventh()
+            ]
+        );
+
+        // Spaces, bar lines, and slash chords mix freely
+        assert_eq!(
+            parse_progression("|C Am/C|F  G7|").unwrap(),
+            vec![
+                (C, ChordFormula::major_triad(), None),
+                (A, ChordFormula::minor_triad(), Some(C)),
+                (F, ChordFormula::major_triad(), None),
+                (G, ChordFormula::dominant_seventh(), None),
+            ]
+        );
+        assert_eq!(parse_progression("C\tE7\n").unwrap().len(), 2);
+
+        assert_eq!(parse_progression("").unwrap(), vec![]);
+        assert_eq!(parse_progression(" | | ").unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_parse_progression_error() {
+        assert_eq!(
+            parse_progression("Xyz"),
+            Err(ParseChordError {
+                token: "Xyz".to_string()
+            })
+        );
+
+        // Reports the first bad token
+        let error = parse_progression("C | Hm7 | Qb").unwrap_err();
+        assert_eq!(error.token, "Hm7");
+        assert_eq!(error.to_string(), "unrecognized chord symbol `Hm7`");
+    }
+
+    #[test]
+    fn test_parse_chord_invalid() {
+        assert_eq!(parse_chord(""), None);
+        assert_eq!(parse_chord("Xyz"), None);
+        assert_eq!(parse_chord("cmaj7"), None);
+        assert_eq!(parse_chord("Cfoo"), None);
+        assert_eq!(parse_chord("C/"), None);
+        assert_eq!(parse_chord("C/X"), None);
+        assert_eq!(parse_chord("Cfoo/E"), None);
+    }
+}
@@ -1,10 +1,18 @@
 //! Note implementation for musical notes.
 
+use crate::audio::{A4_FREQUENCY, CENTS_IN_OCTAVE};
 use crate::octave::Octave;
-use crate::semitone::Semitone;
+use crate::pitch_class::PitchClass;
+use crate::semitone::{Semitone, SEMITONES_IN_OCTAVE};
 use std::fmt;
 use std::ops::{Add, Shl, Shr, Sub};
 
+/// MIDI note number of A4, the reference pitch for frequency conversions.
+pub(crate) const A4_MIDI: u8 = 69;
+
+/// Number of notes in the MIDI range (0-127).
+pub(crate) const MIDI_NOTE_COUNT: usize = 128;
+
 /// A musical note represented by its semitone offset from C.
 ///
 /// Notes are fundamental building blocks in music theory, representing
@@ -41,6 +49,47 @@ impl Note {
         Self(semitone)
     }
 
+    /// Creates a new `Note` only if the value is a valid MIDI note number (0-127).
+    ///
+    /// Use this at MIDI boundaries to reject out-of-range pitches; [`Note::new`]
+    /// accepts any `u8` for general semitone arithmetic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musik_std::Note;
+    ///
+    /// assert_eq!(Note::new_checked(127), Some(Note::new(127)));
+    /// assert_eq!(Note::new_checked(128), None);
+    /// ```
+    pub const fn new_checked(value: u8) -> Option<Self> {
+        if (value as usize) < MIDI_NOTE_COUNT {
+            Some(Self(value))
+        } else {
+            None
+        }
+    }
+
+    /// Creates a note from a pitch class and an octave using MIDI octave numbering.
+    ///
+    /// The note number is `(octave + 1) * 12 + pitch_class`, so middle C is C in
+    /// octave 4. Returns `None` if the result falls outside the representable range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musik_std::{Note, Octave, A, C};
+    ///
+    /// assert_eq!(Note::from_pitch_class(C, Octave::new(4)), Some(Note::new(60)));
+    /// assert_eq!(Note::from_pitch_class(A, Octave::new(4)), Some(Note::new(69)));
+    /// assert_eq!(Note::from_pitch_class(C, Octave::new(-2)), None);
+    /// ```
+    pub fn from_pitch_class(pitch_class: PitchClass, octave: Octave) -> Option<Self> {
+        let value =
+            (octave.value() as i16 + 1) * SEMITONES_IN_OCTAVE as i16 + pitch_class.value() as i16;
+        u8::try_from(value).ok().map(Self)
+    }
+
     /// Returns the semitone offset of this note.
     ///
     /// # Examples
@@ -55,6 +104,20 @@ impl Note {
         self.0
     }
 
+    /// Checks whether this note is within the MIDI range (0-127).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musik_std::Note;
+    ///
+    /// assert!(Note::new(60).is_valid_midi());
+    /// assert!(!Note::new(255).is_valid_midi());
+    /// ```
+    pub const fn is_valid_midi(&self) -> bool {
+        (self.0 as usize) < MIDI_NOTE_COUNT
+    }
+
     /// Returns the semitone offset of this note as a `Semitone`.
     ///
     /// # Examples
@@ -125,9 +188,211 @@ impl Note {
     /// assert_eq!(c4.pitch_class().value(), 0);
     /// assert_eq!(f_sharp.pitch_class().value(), 6);
     /// ```
-    pub const fn pitch_class(self) -> crate::PitchClass {
+    pub const fn pitch_class(self) -> PitchClass {
         self.as_semitone().pitch_class()
     }
+
+    /// Checks whether this note is played on a black key of a piano keyboard.
+    ///
+    /// The black keys are the five accidental pitch classes: C#, D#, F#, G#, and A#.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musik_std::Note;
+    ///
+    /// assert!(Note::new(61).is_black_key()); // C#4
+    /// assert!(!Note::new(62).is_black_key()); // D4
+    /// ```
+    pub const fn is_black_key(&self) -> bool {
+        self.white_key_index().is_none()
+    }
+
+    /// Returns the position of this note among the seven white keys of its octave.
+    ///
+    /// C is 0, D is 1, and so on up to B at 6. Returns `None` for black keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musik_std::Note;
+    ///
+    /// assert_eq!(Note::new(62).white_key_index(), Some(1)); // D4
+    /// assert_eq!(Note::new(71).white_key_index(), Some(6)); // B4
+    /// assert_eq!(Note::new(61).white_key_index(), None); // C#4
+    /// ```
+    pub const fn white_key_index(&self) -> Option<u8> {
+        match self.pitch_class().value() {
+            0 => Some(0),
+            2 => Some(1),
+            4 => Some(2),
+            5 => Some(3),
+            7 => Some(4),
+            9 => Some(5),
+            11 => Some(6),
+            _ => None,
+        }
+    }
+
+    /// Transposes this note by `semitones`, wrapping around the MIDI range (0-127).
+    ///
+    /// Unlike the saturating `Add` and `Sub` operators, moving below 0 continues
+    /// down from 127 and moving above 127 continues up from 0. A note above the
+    /// MIDI range is wrapped into it as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musik_std::Note;
+    ///
+    /// assert_eq!(Note::new(60).transpose_wrapping(7), Note::new(67)); // C4 to G4
+    /// assert_eq!(Note::new(0).transpose_wrapping(-12), Note::new(116)); // Wraps below 0
+    /// assert_eq!(Note::new(127).transpose_wrapping(1), Note::new(0)); // Wraps above 127
+    /// ```
+    pub const fn transpose_wrapping(self, semitones: i8) -> Self {
+        let midi_range = MIDI_NOTE_COUNT as i16;
+        Self((self.0 as i16 + semitones as i16).rem_euclid(midi_range) as u8)
+    }
+
+    /// Returns the equal-tempered frequency of this note in Hz, with A4 = 440 Hz.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musik_std::Note;
+    ///
+    /// assert_eq!(Note::new(69).frequency(), 440.0); // A4
+    /// assert_eq!(Note::new(81).frequency(), 880.0); // A5
+    /// ```
+    pub fn frequency(self) -> f64 {
+        self.frequency_with_reference(A4_FREQUENCY)
+    }
+
+    /// Returns the equal-tempered frequency of this note in Hz for a given A4 reference.
+    ///
+    /// Useful for ensembles that do not tune to A440, such as baroque (A415)
+    /// or some orchestras (A442).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musik_std::Note;
+    ///
+    /// let a4 = Note::new(69);
+    /// assert_eq!(a4.frequency_with_reference(415.0), 415.0);
+    /// assert_eq!(a4.frequency_with_reference(442.0), 442.0);
+    ///
+    /// let a3 = Note::new(57);
+    /// assert_eq!(a3.frequency_with_reference(415.0), 207.5);
+    /// ```
+    pub fn frequency_with_reference(self, a4_hz: f64) -> f64 {
+        let offset = self.0 as f64 - A4_MIDI as f64;
+        a4_hz * 2f64.powf(offset / SEMITONES_IN_OCTAVE as f64)
+    }
+
+    /// Returns the frequency of this note in Hz, with A4 = 440 Hz, detuned by `cents`.
+    ///
+    /// Positive cents shift the pitch up and negative cents shift it down; 100
+    /// cents is one equal-tempered semitone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musik_std::Note;
+    ///
+    /// let a4 = Note::new(69);
+    /// assert_eq!(a4.frequency_detuned(0.0), 440.0);
+    /// assert_eq!(a4.frequency_detuned(1200.0), 880.0);
+    /// assert!((a4.frequency_detuned(-100.0) - Note::new(68).frequency()).abs() < 1e-9);
+    /// ```
+    pub fn frequency_detuned(self, cents: f64) -> f64 {
+        self.frequency() * 2f64.powf(cents / CENTS_IN_OCTAVE)
+    }
+
+    /// Returns the note closest to the given frequency, with A4 = 440 Hz.
+    ///
+    /// Returns `None` if the frequency is not positive or falls outside the
+    /// representable note range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musik_std::Note;
+    ///
+    /// assert_eq!(Note::from_frequency(440.0), Some(Note::new(69)));
+    /// assert_eq!(Note::from_frequency(261.63), Some(Note::new(60)));
+    /// assert_eq!(Note::from_frequency(0.0), None);
+    /// ```
+    pub fn from_frequency(frequency: f64) -> Option<Self> {
+        Self::from_frequency_with_reference(frequency, A4_FREQUENCY)
+    }
+
+    /// Returns the note closest to the given frequency for a given A4 reference.
+    ///
+    /// Returns `None` if either frequency is not positive or the result falls
+    /// outside the representable note range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musik_std::Note;
+    ///
+    /// assert_eq!(Note::from_frequency_with_reference(415.0, 415.0), Some(Note::new(69)));
+    /// assert_eq!(Note::from_frequency_with_reference(440.0, 415.0), Some(Note::new(70)));
+    /// assert_eq!(Note::from_frequency_with_reference(440.0, 0.0), None);
+    /// ```
+    pub fn from_frequency_with_reference(frequency: f64, a4_hz: f64) -> Option<Self> {
+        if !(frequency > 0.0 && a4_hz > 0.0) {
+            return None;
+        }
+
+        let rounded = midi_float_from_frequency(frequency, a4_hz).round();
+
+        if (0.0..=u8::MAX as f64).contains(&rounded) {
+            Some(Self(rounded as u8))
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns the unrounded MIDI note number of a frequency for a given A4 reference.
+///
+/// The fractional part measures the distance from the equal-tempered note below
+/// in semitones, so callers can apply their own rounding or read the detuning.
+/// Frequencies that are not positive give a non-finite result.
+///
+/// # Examples
+///
+/// ```
+/// use musik_std::midi_float_from_frequency;
+///
+/// assert_eq!(midi_float_from_frequency(440.0, 440.0), 69.0); // A4
+/// assert_eq!(midi_float_from_frequency(880.0, 440.0), 81.0); // A5
+///
+/// let quarter_tone_up = 440.0 * 2f64.powf(0.5 / 12.0);
+/// assert!((midi_float_from_frequency(quarter_tone_up, 440.0) - 69.5).abs() < 1e-9);
+/// ```
+pub fn midi_float_from_frequency(frequency: f64, a4_hz: f64) -> f64 {
+    A4_MIDI as f64 + SEMITONES_IN_OCTAVE as f64 * (frequency / a4_hz).log2()
+}
+
+/// Returns the equal-tempered frequency of every MIDI note (0-127) for a given A4 reference.
+///
+/// Index `n` holds the frequency of `Note::new(n)`, so the table can serve as a
+/// lookup in place of calling [`Note::frequency_with_reference`] per note.
+///
+/// # Examples
+///
+/// ```
+/// use musik_std::{frequency_table, Note};
+///
+/// let table = frequency_table(440.0);
+/// assert_eq!(table[69], 440.0); // A4
+/// assert_eq!(table[60], Note::new(60).frequency()); // Middle C
+/// ```
+pub fn frequency_table(a4_hz: f64) -> [f64; MIDI_NOTE_COUNT] {
+    std::array::from_fn(|midi| Note::new(midi as u8).frequency_with_reference(a4_hz))
 }
 
 impl From<u8> for Note {
@@ -338,6 +603,15 @@ impl fmt::Display for Note {
     }
 }
 
+/// Builds the notes for a list of MIDI semitones, shared by the test modules.
+#[cfg(test)]
+pub(crate) fn notes(semitones: &[u8]) -> Vec<Note> {
+    semitones
+        .iter()
+        .map(|&semitone| Note::new(semitone))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -353,6 +627,24 @@ mod tests {
         assert_eq!(d.semitone(), 2);
     }
 
+    #[test]
+    fn test_note_new_checked() {
+        assert_eq!(Note::new_checked(0), Some(Note::new(0)));
+        assert_eq!(Note::new_checked(60), Some(Note::new(60)));
+        assert_eq!(Note::new_checked(127), Some(Note::new(127)));
+        assert_eq!(Note::new_checked(128), None);
+        assert_eq!(Note::new_checked(255), None);
+    }
+
+    #[test]
+    fn test_note_is_valid_midi() {
+        for value in 0..=u8::MAX {
+            let note = Note::new(value);
+            assert_eq!(note.is_valid_midi(), value <= 127);
+            assert_eq!(Note::new_checked(value).is_some(), note.is_valid_midi());
+        }
+    }
+
     #[test]
     fn test_note_const_creation() {
         const C: Note = Note::new(0);
@@ -628,6 +920,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_note_transpose_wrapping() {
+        // Within range it matches plain transposition
+        assert_eq!(Note::new(60).transpose_wrapping(0), Note::new(60));
+        assert_eq!(Note::new(60).transpose_wrapping(12), Note::new(72));
+        assert_eq!(Note::new(60).transpose_wrapping(-12), Note::new(48));
+
+        // C0 down two octaves wraps instead of clamping to 0
+        assert_eq!(Note::new(12).transpose_wrapping(-24), Note::new(116));
+        assert_ne!(
+            Note::new(12).transpose_wrapping(-24),
+            Note::new(12) - Semitone::new(24)
+        );
+        assert_eq!(Note::new(0).transpose_wrapping(-1), Note::new(127));
+        assert_eq!(Note::new(120).transpose_wrapping(10), Note::new(2));
+        assert_eq!(Note::new(0).transpose_wrapping(i8::MIN), Note::new(0));
+        assert_eq!(Note::new(127).transpose_wrapping(i8::MAX), Note::new(126));
+
+        // Results always stay in the MIDI range
+        for semitone in [0, 64, 127, 200, 255] {
+            for semitones in [i8::MIN, -1, 0, 1, i8::MAX] {
+                assert!(Note::new(semitone)
+                    .transpose_wrapping(semitones)
+                    .is_valid_midi());
+            }
+        }
+
+        // Wrapping round trip
+        for semitone in 0..=127 {
+            let note = Note::new(semitone);
+            assert_eq!(note.transpose_wrapping(-100).transpose_wrapping(100), note);
+        }
+    }
+
     #[test]
     fn test_note_add_sub_identity_operations() {
         let notes = [0, 5, 12, 24, 48, 60, 72, 127];
@@ -806,6 +1132,86 @@ mod tests {
         assert_eq!((near_max >> 1u8).semitone(), 255); // 244 + 12 = saturates to 255
     }
 
+    #[test]
+    fn test_note_frequency() {
+        assert_eq!(Note::new(69).frequency(), 440.0); // A4
+        assert_eq!(Note::new(57).frequency(), 220.0); // A3
+        assert_eq!(Note::new(81).frequency(), 880.0); // A5
+        assert!((Note::new(60).frequency() - 261.625_565_300_6).abs() < 1e-9); // C4
+    }
+
+    #[test]
+    fn test_note_frequency_with_reference() {
+        // Baroque pitch: A4 is exactly the reference
+        assert_eq!(Note::new(69).frequency_with_reference(415.0), 415.0);
+        assert_eq!(Note::new(69).frequency_with_reference(442.0), 442.0);
+
+        // Other notes scale proportionally to the reference
+        let middle_c = Note::new(60);
+        let ratio = middle_c.frequency_with_reference(415.0) / middle_c.frequency();
+        assert!((ratio - 415.0 / 440.0).abs() < 1e-12);
+
+        assert_eq!(
+            Note::new(69).frequency_with_reference(440.0),
+            Note::new(69).frequency()
+        );
+    }
+
+    #[test]
+    fn test_note_frequency_detuned() {
+        let a4 = Note::new(69);
+        assert_eq!(a4.frequency_detuned(0.0), a4.frequency());
+        assert_eq!(a4.frequency_detuned(1200.0), 880.0);
+        assert_eq!(a4.frequency_detuned(-1200.0), 220.0);
+
+        // A semitone down from A4 is G#4
+        assert!((a4.frequency_detuned(-100.0) - Note::new(68).frequency()).abs() < 1e-9);
+
+        // Detuning round-trips through cents_between
+        for cents in [-37.5, -5.0, 7.0, 50.0] {
+            let detuned = Note::new(60).frequency_detuned(cents);
+            let measured = crate::audio::cents_between(Note::new(60).frequency(), detuned);
+            assert!((measured - cents).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_note_from_frequency_with_reference() {
+        assert_eq!(Note::from_frequency(440.0), Some(Note::new(69)));
+        assert_eq!(
+            Note::from_frequency_with_reference(415.0, 415.0),
+            Some(Note::new(69))
+        );
+        assert_eq!(
+            Note::from_frequency_with_reference(207.5, 415.0),
+            Some(Note::new(57))
+        );
+
+        // Slightly detuned frequencies round to the nearest note
+        assert_eq!(Note::from_frequency(445.0), Some(Note::new(69)));
+        assert_eq!(Note::from_frequency(435.0), Some(Note::new(69)));
+
+        // Round trip for every MIDI note and several references
+        for reference in [415.0, 440.0, 442.0] {
+            for midi in 0..=127u8 {
+                let note = Note::new(midi);
+                let frequency = note.frequency_with_reference(reference);
+                assert_eq!(
+                    Note::from_frequency_with_reference(frequency, reference),
+                    Some(note)
+                );
+            }
+        }
+
+        // Invalid input
+        assert_eq!(Note::from_frequency(0.0), None);
+        assert_eq!(Note::from_frequency(-440.0), None);
+        assert_eq!(Note::from_frequency(f64::NAN), None);
+        assert_eq!(Note::from_frequency_with_reference(440.0, 0.0), None);
+        assert_eq!(Note::from_frequency(1.0), None); // Below MIDI 0
+        assert_eq!(Note::from_frequency(1.0e9), None); // Above u8 range
+    }
+
     #[test]
     fn test_note_pitch_class() {
         // Test that notes in different octaves have the same pitch class
@@ -879,4 +1285,128 @@ mod tests {
         let min_note = Note::new(0);
         assert_eq!(min_note.pitch_class().value(), 0); // Should be C
     }
+
+    #[test]
+    fn test_midi_float_from_frequency() {
+        assert_eq!(midi_float_from_frequency(440.0, 440.0), 69.0);
+        assert_eq!(midi_float_from_frequency(220.0, 440.0), 57.0);
+        assert_eq!(midi_float_from_frequency(415.0, 415.0), 69.0);
+
+        let quarter_tone_up = 440.0 * 2f64.powf(0.5 / 12.0);
+        assert!((midi_float_from_frequency(quarter_tone_up, 440.0) - 69.5).abs() < 1e-9);
+
+        // Inverse of the equal-tempered frequency
+        for midi in 0..=127u8 {
+            let frequency = Note::new(midi).frequency();
+            assert!((midi_float_from_frequency(frequency, 440.0) - midi as f64).abs() < 1e-9);
+        }
+
+        assert!(!midi_float_from_frequency(0.0, 440.0).is_finite());
+        assert!(midi_float_from_frequency(-1.0, 440.0).is_nan());
+    }
+
+    #[test]
+    fn test_frequency_table() {
+        let table = frequency_table(440.0);
+
+        assert_eq!(table.len(), 128);
+        assert_eq!(table[69], 440.0);
+        assert_eq!(table[60], Note::new(60).frequency());
+        assert_eq!(table[0], Note::new(0).frequency());
+        assert_eq!(table[127], Note::new(127).frequency());
+
+        let semitone_ratio = 2f64.powf(1.0 / 12.0);
+        for pair in table.windows(2) {
+            assert!((pair[1] / pair[0] - semitone_ratio).abs() < 1e-12);
+        }
+
+        // Alternative reference
+        let baroque = frequency_table(415.0);
+        assert_eq!(baroque[69], 415.0);
+        assert_eq!(baroque[57], 207.5);
+    }
+
+    #[test]
+    fn test_note_is_black_key() {
+        assert!(Note::new(61).is_black_key()); // C#4
+        assert!(!Note::new(62).is_black_key()); // D4
+
+        // Five black keys per octave
+        let black_keys: Vec<u8> = (60..72)
+            .filter(|&value| Note::new(value).is_black_key())
+            .collect();
+        assert_eq!(black_keys, vec![61, 63, 66, 68, 70]);
+
+        // Black keys repeat in every octave
+        for value in 0..=u8::MAX - 12 {
+            assert_eq!(
+                Note::new(value).is_black_key(),
+                Note::new(value + 12).is_black_key()
+            );
+        }
+    }
+
+    #[test]
+    fn test_note_white_key_index() {
+        let indices: Vec<Option<u8>> = (60..72)
+            .map(|value| Note::new(value).white_key_index())
+            .collect();
+        assert_eq!(
+            indices,
+            vec![
+                Some(0),
+                None,
+                Some(1),
+                None,
+                Some(2),
+                Some(3),
+                None,
+                Some(4),
+                None,
+                Some(5),
+                None,
+                Some(6)
+            ]
+        );
+
+        assert_eq!(Note::new(0).white_key_index(), Some(0));
+        assert_eq!(Note::new(127).white_key_index(), Some(4)); // G9
+    }
+
+    #[test]
+    fn test_note_from_pitch_class() {
+        use crate::pitch_class::{A, B, C, G};
+
+        assert_eq!(
+            Note::from_pitch_class(C, Octave::new(4)),
+            Some(Note::new(60))
+        );
+        assert_eq!(
+            Note::from_pitch_class(A, Octave::new(4)),
+            Some(Note::new(69))
+        );
+        assert_eq!(
+            Note::from_pitch_class(C, Octave::new(-1)),
+            Some(Note::new(0))
+        );
+        assert_eq!(
+            Note::from_pitch_class(G, Octave::new(9)),
+            Some(Note::new(127))
+        );
+
+        // Round-trips through pitch_class() and octave()
+        for value in 0..=u8::MAX {
+            let note = Note::new(value);
+            assert_eq!(
+                Note::from_pitch_class(note.pitch_class(), note.octave()),
+                Some(note)
+            );
+        }
+
+        // Out of range
+        assert_eq!(Note::from_pitch_class(B, Octave::new(-2)), None);
+        assert_eq!(Note::from_pitch_class(B, Octave::new(20)), None);
+        assert_eq!(Note::from_pitch_class(C, Octave::new(i8::MAX)), None);
+        assert_eq!(Note::from_pitch_class(C, Octave::new(i8::MIN)), None);
+    }
 }
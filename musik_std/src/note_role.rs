@@ -0,0 +1,63 @@
+//! Harmonic roles of melody notes over a chord.
+//!
+//! This module provides the `NoteRole` enum used to color melody notes by how
+//! they relate to the underlying chord.
+
+use std::fmt;
+
+/// The role a note plays against a chord.
+///
+/// # Examples
+/// ```
+/// use musik_std::{ChordFormula, NoteRole, C, D, E, F};
+///
+/// let major_seventh = ChordFormula::major_seventh();
+/// assert_eq!(major_seventh.classify_note(C, E), NoteRole::ChordTone);
+/// assert_eq!(major_seventh.classify_note(C, D), NoteRole::Tension);
+/// assert_eq!(major_seventh.classify_note(C, F), NoteRole::Avoid);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NoteRole {
+    /// The note is one of the chord's tones
+    ChordTone,
+    /// The note is an extension that can be added over the chord (9, 11, 13, ...)
+    Tension,
+    /// The note clashes with a chord tone a semitone below it
+    Avoid,
+}
+
+impl NoteRole {
+    /// Get the name of the role.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::NoteRole;
+    ///
+    /// assert_eq!(NoteRole::ChordTone.name(), "Chord Tone");
+    /// ```
+    pub const fn name(&self) -> &'static str {
+        match self {
+            NoteRole::ChordTone => "Chord Tone",
+            NoteRole::Tension => "Tension",
+            NoteRole::Avoid => "Avoid",
+        }
+    }
+}
+
+impl fmt::Display for NoteRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_note_role_display() {
+        assert_eq!(format!("{}", NoteRole::ChordTone), "Chord Tone");
+        assert_eq!(format!("{}", NoteRole::Tension), "Tension");
+        assert_eq!(format!("{}", NoteRole::Avoid), "Avoid");
+    }
+}
@@ -144,6 +144,43 @@ impl PitchClass {
         }
     }
 
+    /// Parses a note name: an uppercase letter with an optional sharp or flat.
+    ///
+    /// Accepts `#` or `♯` for sharps and `b` or `♭` for flats. Returns `None`
+    /// for anything else.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musik_std::{PitchClass, B_FLAT, C, F_SHARP};
+    ///
+    /// assert_eq!(PitchClass::from_name("C"), Some(C));
+    /// assert_eq!(PitchClass::from_name("F#"), Some(F_SHARP));
+    /// assert_eq!(PitchClass::from_name("Bb"), Some(B_FLAT));
+    /// assert_eq!(PitchClass::from_name("H"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<Self> {
+        let mut chars = name.chars();
+        let natural = match chars.next()? {
+            'C' => 0,
+            'D' => 2,
+            'E' => 4,
+            'F' => 5,
+            'G' => 7,
+            'A' => 9,
+            'B' => 11,
+            _ => return None,
+        };
+
+        let value = match (chars.next(), chars.next()) {
+            (None, _) => natural,
+            (Some('#' | '♯'), None) => natural + 1,
+            (Some('b' | '♭'), None) => natural + Self::COUNT - 1,
+            _ => return None,
+        };
+        Some(Self::new(value))
+    }
+
     /// Returns an iterator over all 12 pitch classes.
     ///
     /// # Examples
@@ -243,6 +280,25 @@ mod tests {
         assert_eq!(B.name(), "B");
     }
 
+    #[test]
+    fn test_from_name() {
+        // Round-trips with name()
+        for pitch_class in PitchClass::all() {
+            assert_eq!(PitchClass::from_name(pitch_class.name()), Some(pitch_class));
+        }
+
+        assert_eq!(PitchClass::from_name("Db"), Some(D_FLAT));
+        assert_eq!(PitchClass::from_name("E♭"), Some(D_SHARP));
+        assert_eq!(PitchClass::from_name("G♯"), Some(G_SHARP));
+        assert_eq!(PitchClass::from_name("Cb"), Some(B)); // Wraps below C
+        assert_eq!(PitchClass::from_name("B#"), Some(C)); // Wraps above B
+
+        assert_eq!(PitchClass::from_name(""), None);
+        assert_eq!(PitchClass::from_name("c"), None);
+        assert_eq!(PitchClass::from_name("C##"), None);
+        assert_eq!(PitchClass::from_name("Cm"), None);
+    }
+
     #[test]
     fn test_display() {
         assert_eq!(format!("{}", C), "C");
@@ -14,10 +14,18 @@
 //! ```
 
 // Re-export commonly used types and traits
+pub use crate::analysis::{analyze, detect_key, AnalysisResult};
+pub use crate::chord_descriptor::ChordDescriptor;
 pub use crate::chord_formula::ChordFormula;
+pub use crate::chord_quality::ChordQuality;
 pub use crate::degree_alteration::DegreeAlteration;
+pub use crate::directed_interval::DirectedInterval;
 pub use crate::formula_degree::FormulaDegree;
+pub use crate::instruments::TransposingInstrument;
+pub use crate::melody_generator::MelodyGenerator;
+pub use crate::micro_pitch::MicroPitch;
 pub use crate::note::Note;
+pub use crate::note_role::NoteRole;
 pub use crate::octave::Octave;
 pub use crate::pitch_class::{
     PitchClass, A, A_FLAT, A_SHARP, B, B_FLAT, C, C_SHARP, D, D_FLAT, D_SHARP, E, E_FLAT, F,
@@ -25,6 +33,9 @@ pub use crate::pitch_class::{
 };
 pub use crate::scale_formula::ScaleFormula;
 pub use crate::semitone::{Semitone, SEMITONES_IN_OCTAVE};
+pub use crate::tendency::Tendency;
+pub use crate::transpose::{OverflowPolicy, Transpose};
+pub use crate::tuning::{EqualTemperament, JustIntonation, Pythagorean, Tuning};
 
 // Future additions will include:
 // pub use crate::interval::Interval;
@@ -4,10 +4,99 @@
 //! using bit flags, where each bit position indicates whether a semitone is
 //! present in the scale.
 
+use crate::chord_quality::ChordQuality;
+use crate::degree_alteration::DegreeAlteration;
+use crate::melody::Contour;
 use crate::note::Note;
+use crate::octave::Octave;
+use crate::pitch_class::PitchClass;
 use crate::semitone::{Semitone, SEMITONES_IN_OCTAVE};
+use crate::tendency::Tendency;
 use std::fmt;
 
+#[cfg(feature = "rand")]
+use crate::chord_formula::ChordFormula;
+#[cfg(feature = "rand")]
+use rand::{distributions::WeightedIndex, rngs::StdRng, Rng, SeedableRng};
+
+/// Scale degrees grouped by harmonic function: tonic, subdominant, and dominant.
+#[cfg(feature = "rand")]
+const FUNCTION_DEGREES: [&[u8]; 3] = [&[1, 6, 3], &[4, 2], &[5, 7]];
+
+/// Relative weights of moving from each harmonic function (row) to the next (column).
+///
+/// Dominants never fall back to the subdominant, and lean strongly towards the tonic.
+#[cfg(feature = "rand")]
+const FUNCTION_WEIGHTS: [[u32; 3]; 3] = [[1, 2, 2], [1, 1, 3], [4, 0, 1]];
+
+/// Stable scale degrees, the tonic triad, that active degrees resolve to.
+const STABLE_DEGREES: [u8; 3] = [1, 3, 5];
+
+/// Number of notes in a heptatonic scale, the scales that have tendency tones.
+const HEPTATONIC_NOTE_COUNT: usize = 7;
+
+/// Scale formulas recognized by [`ScaleFormula::name`] and [`ScaleFormula::from_name`].
+const NAMED_SCALES: [(ScaleFormula, &str); 17] = [
+    (ScaleFormula::major(), "Major"),
+    (ScaleFormula::dorian(), "Dorian"),
+    (ScaleFormula::phrygian(), "Phrygian"),
+    (ScaleFormula::lydian(), "Lydian"),
+    (ScaleFormula::mixolydian(), "Mixolydian"),
+    (ScaleFormula::minor(), "Minor"),
+    (ScaleFormula::locrian(), "Locrian"),
+    (ScaleFormula::harmonic_minor(), "Harmonic Minor"),
+    (ScaleFormula::altered(), "Altered"),
+    (ScaleFormula::pentatonic_major(), "Pentatonic Major"),
+    (ScaleFormula::pentatonic_minor(), "Pentatonic Minor"),
+    (ScaleFormula::blues(), "Blues"),
+    (ScaleFormula::whole_tone(), "Whole Tone"),
+    (ScaleFormula::octatonic_wh(), "Octatonic Whole-Half"),
+    (ScaleFormula::octatonic_hw(), "Octatonic Half-Whole"),
+    (ScaleFormula::augmented_scale(), "Augmented"),
+    (ScaleFormula::chromatic(), "Chromatic"),
+];
+
+/// Default degree spelling for each semitone above the root, relative to the major scale.
+const DEGREE_SPELLINGS: [(DegreeAlteration, u8); SEMITONES_IN_OCTAVE as usize] = [
+    (DegreeAlteration::None, 1),
+    (DegreeAlteration::Flat, 2),
+    (DegreeAlteration::None, 2),
+    (DegreeAlteration::Flat, 3),
+    (DegreeAlteration::None, 3),
+    (DegreeAlteration::None, 4),
+    (DegreeAlteration::Flat, 5),
+    (DegreeAlteration::None, 5),
+    (DegreeAlteration::Flat, 6),
+    (DegreeAlteration::None, 6),
+    (DegreeAlteration::Flat, 7),
+    (DegreeAlteration::None, 7),
+];
+
+/// Note letters, in ascending order from C.
+const LETTERS: [char; 7] = ['C', 'D', 'E', 'F', 'G', 'A', 'B'];
+
+/// Pitch class value of each natural letter in [`LETTERS`].
+const NATURALS: [u8; 7] = [0, 2, 4, 5, 7, 9, 11];
+
+/// Generic interval names by letter distance (0 = unison).
+const INTERVAL_NUMBERS: [&str; 7] = [
+    "unison", "second", "third", "fourth", "fifth", "sixth", "seventh",
+];
+
+/// Semitones of the perfect or major interval for each letter distance.
+const INTERVAL_REFERENCES: [u8; 7] = [0, 2, 4, 5, 7, 9, 11];
+
+/// Movable-do solfège syllables of the major-scale degrees 1-7.
+const NATURAL_SYLLABLES: [&str; 7] = ["do", "re", "mi", "fa", "sol", "la", "ti"];
+
+/// Movable-do solfège syllables for each semitone above the tonic, used for
+/// notes outside the scale.
+///
+/// Chromatic notes take the lowered syllable, except the raised fourth (fi).
+const SOLFEGE_SYLLABLES: [&str; SEMITONES_IN_OCTAVE as usize] = [
+    "do", "ra", "re", "me", "mi", "fa", "fi", "sol", "le", "la", "te", "ti",
+];
+
 /// Represents a musical scale formula using bit flags.
 ///
 /// Each bit position corresponds to a semitone offset from the root note.
@@ -70,7 +159,10 @@ use std::fmt;
 /// let custom = ScaleFormula::from_semitones(&[0, 2, 4, 7, 9]);
 /// assert_eq!(custom.note_count(), 5);
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///
+/// Formulas are ordered by their bit pattern, so a `(PitchClass, ScaleFormula)`
+/// pair can be used as a key in both hashed and ordered collections.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct ScaleFormula(pub u32);
 
 impl ScaleFormula {
@@ -201,6 +293,126 @@ impl ScaleFormula {
         ScaleFormula(0b010110101101)
     }
 
+    /// Create a harmonic minor scale formula.
+    ///
+    /// Pattern: 1, 2, ♭3, 4, 5, ♭6, 7
+    /// Semitones: 0, 2, 3, 5, 7, 8, 11
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ScaleFormula;
+    ///
+    /// let harmonic_minor = ScaleFormula::harmonic_minor();
+    /// assert_eq!(harmonic_minor.note_count(), 7);
+    /// assert!(harmonic_minor.contains_semitone(11)); // Raised 7th
+    /// ```
+    pub const fn harmonic_minor() -> Self {
+        // Bits: 11 10  9  8  7  6  5  4  3  2  1  0
+        //        7  ♭7  6  ♭6  5 ♭5  4  3 ♭3  2 ♭2  1
+        //        1   0  0   1  1  0  1  0  1  1  0  1
+        ScaleFormula(0b100110101101)
+    }
+
+    /// Create a Dorian mode scale formula.
+    ///
+    /// Pattern: 1, 2, ♭3, 4, 5, 6, ♭7
+    /// Semitones: 0, 2, 3, 5, 7, 9, 10
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ScaleFormula;
+    ///
+    /// let dorian = ScaleFormula::dorian();
+    /// assert_eq!(dorian.note_count(), 7);
+    /// assert!(dorian.contains_semitone(9)); // Major 6th
+    /// ```
+    pub const fn dorian() -> Self {
+        // Bits: 11 10  9  8  7  6  5  4  3  2  1  0
+        //        7  ♭7  6  ♭6  5 ♭5  4  3 ♭3  2 ♭2  1
+        //        0   1  1   0  1  0  1  0  1  1  0  1
+        ScaleFormula(0b011010101101)
+    }
+
+    /// Create a Phrygian mode scale formula.
+    ///
+    /// Pattern: 1, ♭2, ♭3, 4, 5, ♭6, ♭7
+    /// Semitones: 0, 1, 3, 5, 7, 8, 10
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ScaleFormula;
+    ///
+    /// let phrygian = ScaleFormula::phrygian();
+    /// assert_eq!(phrygian.note_count(), 7);
+    /// assert!(phrygian.contains_semitone(1)); // ♭2
+    /// ```
+    pub const fn phrygian() -> Self {
+        // Bits: 11 10  9  8  7  6  5  4  3  2  1  0
+        //        7  ♭7  6  ♭6  5 ♭5  4  3 ♭3  2 ♭2  1
+        //        0   1  0   1  1  0  1  0  1  0  1  1
+        ScaleFormula(0b010110101011)
+    }
+
+    /// Create a Lydian mode scale formula.
+    ///
+    /// Pattern: 1, 2, 3, ♯4, 5, 6, 7
+    /// Semitones: 0, 2, 4, 6, 7, 9, 11
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ScaleFormula;
+    ///
+    /// let lydian = ScaleFormula::lydian();
+    /// assert_eq!(lydian.note_count(), 7);
+    /// assert!(lydian.contains_semitone(6)); // ♯4
+    /// ```
+    pub const fn lydian() -> Self {
+        // Bits: 11 10  9  8  7  6  5  4  3  2  1  0
+        //        7  ♭7  6  ♭6  5 ♭5  4  3 ♭3  2 ♭2  1
+        //        1   0  1   0  1  1  0  1  0  1  0  1
+        ScaleFormula(0b101011010101)
+    }
+
+    /// Create a Mixolydian mode scale formula.
+    ///
+    /// Pattern: 1, 2, 3, 4, 5, 6, ♭7
+    /// Semitones: 0, 2, 4, 5, 7, 9, 10
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ScaleFormula;
+    ///
+    /// let mixolydian = ScaleFormula::mixolydian();
+    /// assert_eq!(mixolydian.note_count(), 7);
+    /// assert!(mixolydian.contains_semitone(10)); // ♭7
+    /// ```
+    pub const fn mixolydian() -> Self {
+        // Bits: 11 10  9  8  7  6  5  4  3  2  1  0
+        //        7  ♭7  6  ♭6  5 ♭5  4  3 ♭3  2 ♭2  1
+        //        0   1  1   0  1  0  1  1  0  1  0  1
+        ScaleFormula(0b011010110101)
+    }
+
+    /// Create a Locrian mode scale formula.
+    ///
+    /// Pattern: 1, ♭2, ♭3, 4, ♭5, ♭6, ♭7
+    /// Semitones: 0, 1, 3, 5, 6, 8, 10
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ScaleFormula;
+    ///
+    /// let locrian = ScaleFormula::locrian();
+    /// assert_eq!(locrian.note_count(), 7);
+    /// assert!(locrian.contains_semitone(6)); // ♭5
+    /// ```
+    pub const fn locrian() -> Self {
+        // Bits: 11 10  9  8  7  6  5  4  3  2  1  0
+        //        7  ♭7  6  ♭6  5 ♭5  4  3 ♭3  2 ♭2  1
+        //        0   1  0   1  0  1  1  0  1  0  1  1
+        ScaleFormula(0b010101101011)
+    }
+
     /// Create a pentatonic major scale formula.
     ///
     /// Pattern: 1, 2, 3, 5, 6
@@ -259,6 +471,107 @@ impl ScaleFormula {
         ScaleFormula(0b010011101001)
     }
 
+    /// Create a whole-tone scale formula.
+    ///
+    /// Pattern: 1, 2, 3, ♯4, ♯5, ♭7
+    /// Semitones: 0, 2, 4, 6, 8, 10
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ScaleFormula;
+    ///
+    /// let whole_tone = ScaleFormula::whole_tone();
+    /// assert_eq!(whole_tone.note_count(), 6);
+    /// assert_eq!(whole_tone.largest_gap(), 2);
+    /// ```
+    pub const fn whole_tone() -> Self {
+        // Bits: 11 10  9  8  7  6  5  4  3  2  1  0
+        //        7  ♭7  6  ♭6  5 ♭5  4  3 ♭3  2 ♭2  1
+        //        0   1  0   1  0  1  0  1  0  1  0  1
+        ScaleFormula(0b010101010101)
+    }
+
+    /// Create a whole-half octatonic (diminished) scale formula.
+    ///
+    /// Pattern: 1, 2, ♭3, 4, ♭5, ♭6, 6, 7
+    /// Semitones: 0, 2, 3, 5, 6, 8, 9, 11
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ScaleFormula;
+    ///
+    /// let octatonic = ScaleFormula::octatonic_wh();
+    /// assert_eq!(octatonic.note_count(), 8);
+    /// assert_eq!(octatonic.symmetry_period(), 3);
+    /// ```
+    pub const fn octatonic_wh() -> Self {
+        // Bits: 11 10  9  8  7  6  5  4  3  2  1  0
+        //        7  ♭7  6  ♭6  5 ♭5  4  3 ♭3  2 ♭2  1
+        //        1   0  1   1  0  1  1  0  1  1  0  1
+        ScaleFormula(0b101101101101)
+    }
+
+    /// Create a half-whole octatonic (dominant diminished) scale formula.
+    ///
+    /// Pattern: 1, ♭2, ♭3, 3, ♭5, 5, 6, ♭7
+    /// Semitones: 0, 1, 3, 4, 6, 7, 9, 10
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ScaleFormula;
+    ///
+    /// let octatonic = ScaleFormula::octatonic_hw();
+    /// assert_eq!(octatonic.note_count(), 8);
+    /// assert_eq!(octatonic.mode(2), Some(ScaleFormula::octatonic_wh()));
+    /// ```
+    pub const fn octatonic_hw() -> Self {
+        // Bits: 11 10  9  8  7  6  5  4  3  2  1  0
+        //        7  ♭7  6  ♭6  5 ♭5  4  3 ♭3  2 ♭2  1
+        //        0   1  1   0  1  1  0  1  1  0  1  1
+        ScaleFormula(0b011011011011)
+    }
+
+    /// Create an augmented (hexatonic) scale formula.
+    ///
+    /// Pattern: 1, ♭3, 3, 5, ♯5, 7
+    /// Semitones: 0, 3, 4, 7, 8, 11
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ScaleFormula;
+    ///
+    /// let augmented = ScaleFormula::augmented_scale();
+    /// assert_eq!(augmented.note_count(), 6);
+    /// assert_eq!(augmented.symmetry_period(), 4);
+    /// ```
+    pub const fn augmented_scale() -> Self {
+        // Bits: 11 10  9  8  7  6  5  4  3  2  1  0
+        //        7  ♭7  6  ♭6  5 ♭5  4  3 ♭3  2 ♭2  1
+        //        1   0  0   1  1  0  0  1  1  0  0  1
+        ScaleFormula(0b100110011001)
+    }
+
+    /// Create an altered (super-Locrian) scale formula, the seventh mode of melodic minor.
+    ///
+    /// Pattern: 1, ♭2, ♭3, 3, ♭5, ♭6, ♭7 (read over a dominant as ♭9, ♯9, ♯11, ♭13)
+    /// Semitones: 0, 1, 3, 4, 6, 8, 10
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ScaleFormula;
+    ///
+    /// let altered = ScaleFormula::altered();
+    /// assert_eq!(altered.note_count(), 7);
+    /// assert!(altered.contains_semitone(4)); // Major 3rd
+    /// assert!(altered.contains_semitone(10)); // Minor 7th
+    /// ```
+    pub const fn altered() -> Self {
+        // Bits: 11 10  9  8  7  6  5  4  3  2  1  0
+        //        7  ♭7  6  ♭6  5 ♭5  4  3 ♭3  2 ♭2  1
+        //        0   1  0   1  0  1  0  1  1  0  1  1
+        ScaleFormula(0b010101011011)
+    }
+
     /// Create a scale formula from a list of semitone offsets.
     ///
     /// # Examples
@@ -289,6 +602,44 @@ impl ScaleFormula {
         ScaleFormula(bits)
     }
 
+    /// Adds a semitone to the scale formula.
+    ///
+    /// Semitones outside the two-octave range (0-23) leave the formula unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ScaleFormula;
+    ///
+    /// // Add the blue note (♭5) to the minor pentatonic
+    /// let blues = ScaleFormula::pentatonic_minor().with_semitone(6);
+    /// assert_eq!(blues, ScaleFormula::blues());
+    /// ```
+    pub const fn with_semitone(self, semitone: u8) -> Self {
+        if semitone >= 2 * SEMITONES_IN_OCTAVE {
+            return self;
+        }
+        ScaleFormula(self.0 | (1 << semitone))
+    }
+
+    /// Removes a semitone from the scale formula.
+    ///
+    /// Semitones outside the two-octave range (0-23) leave the formula unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ScaleFormula;
+    ///
+    /// // Dropping the 4th and 7th of major gives the major pentatonic
+    /// let pentatonic = ScaleFormula::major().without_semitone(5).without_semitone(11);
+    /// assert_eq!(pentatonic, ScaleFormula::pentatonic_major());
+    /// ```
+    pub const fn without_semitone(self, semitone: u8) -> Self {
+        if semitone >= 2 * SEMITONES_IN_OCTAVE {
+            return self;
+        }
+        ScaleFormula(self.0 & !(1 << semitone))
+    }
+
     /// Check if a semitone is present in the scale formula.
     ///
     /// # Examples
@@ -342,13 +693,26 @@ impl ScaleFormula {
     /// assert_eq!(ext_semitones, vec![0, 2, 4, 5, 7, 9, 11, 12, 14, 16, 17, 19, 21, 23]);
     /// ```
     pub fn semitones(&self) -> Vec<u8> {
-        let mut result = Vec::new();
-        for i in 0..(2 * SEMITONES_IN_OCTAVE) {
-            if self.contains_semitone(i) {
-                result.push(i);
-            }
-        }
-        result
+        self.iter_semitones().collect()
+    }
+
+    /// Iterate over the semitone offsets present in the scale without allocating.
+    ///
+    /// Yields the same sequence as [`semitones`](Self::semitones), in ascending order.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ScaleFormula;
+    ///
+    /// let pentatonic = ScaleFormula::pentatonic_major();
+    /// let mut semitones = pentatonic.iter_semitones();
+    /// assert_eq!(semitones.next(), Some(0));
+    /// assert_eq!(semitones.next(), Some(2));
+    ///
+    /// assert_eq!(ScaleFormula::blues().iter_semitones().count(), 6);
+    /// ```
+    pub fn iter_semitones(&self) -> impl Iterator<Item = u8> + '_ {
+        (0..(2 * SEMITONES_IN_OCTAVE)).filter(move |&semitone| self.contains_semitone(semitone))
     }
 
     /// Generate all notes in the scale starting from the given root note.
@@ -390,131 +754,1239 @@ impl ScaleFormula {
     /// assert_eq!(note_count, 7);
     /// ```
     pub fn notes_from_root(&self, root: Note) -> impl Iterator<Item = Note> + '_ {
-        self.semitones()
-            .into_iter()
+        self.iter_semitones()
             .map(move |semitone_offset| root + Semitone::new(semitone_offset))
     }
 
-    /// Get the internal bit representation.
+    /// Get the equal-tempered frequency in Hz of a scale degree, for the scale
+    /// starting on `root` in `octave`.
+    ///
+    /// Degrees are 1-based and count up from the root note, so the scale may
+    /// continue into the next octave: degree 3 of A major from A4 is C♯5. `a4`
+    /// is the reference frequency of A4.
+    /// Returns `None` for a degree outside the formula or a note outside the
+    /// `Note` range.
     ///
     /// # Examples
     /// ```
-    /// use musik_std::ScaleFormula;
+    /// use musik_std::{Note, Octave, ScaleFormula, C};
     ///
     /// let major = ScaleFormula::major();
-    /// let bits = major.bits();
-    /// assert_eq!(bits, 0b101010110101);
-    ///
-    /// // Extended formulas can have bits in second octave
-    /// let extended = ScaleFormula::major_extended();
-    /// assert!(extended.bits() > 0xFFF); // Uses more than 12 bits
+    /// assert_eq!(major.degree_frequency(C, 1, Octave::new(4), 440.0), Some(Note::new(60).frequency()));
+    /// assert_eq!(major.degree_frequency(C, 5, Octave::new(4), 440.0), Some(Note::new(67).frequency()));
+    /// assert_eq!(major.degree_frequency(C, 8, Octave::new(4), 440.0), None);
     /// ```
-    pub const fn bits(&self) -> u32 {
-        self.0
+    pub fn degree_frequency(
+        &self,
+        root: PitchClass,
+        degree: u8,
+        octave: Octave,
+        a4: f64,
+    ) -> Option<f64> {
+        let semitone = self.iter_semitones().nth(degree.checked_sub(1)? as usize)?;
+        let root_note = Note::from_pitch_class(root, octave)?;
+        let note = root_note.semitone().checked_add(semitone)?;
+        Some(Note::new(note).frequency_with_reference(a4))
     }
 
-    /// Check if the scale formula is empty (no notes).
+    /// Check if a pitch class belongs to the scale built on `root`.
+    ///
+    /// The interval from `root` is reduced to a single octave, so a pitch class
+    /// is found whether the formula places it in the first or second octave.
     ///
     /// # Examples
     /// ```
-    /// use musik_std::ScaleFormula;
+    /// use musik_std::{ScaleFormula, C, E, F_SHARP, G};
     ///
-    /// assert!(ScaleFormula::empty().is_empty());
-    /// assert!(!ScaleFormula::major().is_empty());
+    /// let major = ScaleFormula::major();
+    /// assert!(major.contains_pitch_class(C, E));
+    /// assert!(!major.contains_pitch_class(C, F_SHARP));
+    /// assert!(major.contains_pitch_class(G, F_SHARP)); // G major has F♯
     /// ```
-    pub const fn is_empty(&self) -> bool {
-        self.0 == 0
+    pub const fn contains_pitch_class(&self, root: PitchClass, pitch_class: PitchClass) -> bool {
+        let interval =
+            (pitch_class.value() + SEMITONES_IN_OCTAVE - root.value()) % SEMITONES_IN_OCTAVE;
+        self.contains_semitone(interval) || self.contains_semitone(interval + SEMITONES_IN_OCTAVE)
     }
 
-    /// Check if the scale contains the root note (semitone 0).
+    /// Get a 12-bit mask of the pitch classes in the scale built on `root`.
+    ///
+    /// Bit `n` is set when the pitch class with value `n` belongs to the scale,
+    /// so membership tests in tight loops reduce to a single bit test.
     ///
     /// # Examples
     /// ```
-    /// use musik_std::ScaleFormula;
+    /// use musik_std::{ScaleFormula, F, F_SHARP, G};
     ///
-    /// assert!(ScaleFormula::major().has_root());
-    /// assert!(!ScaleFormula::empty().has_root());
+    /// let g_major = ScaleFormula::major().membership_mask(G);
+    /// assert_eq!(g_major, 0b1010_1101_0101);
+    /// assert_ne!(g_major & (1 << F_SHARP.value()), 0);
+    /// assert_eq!(g_major & (1 << F.value()), 0);
     /// ```
-    pub const fn has_root(&self) -> bool {
-        self.contains_semitone(0)
+    pub const fn membership_mask(&self, root: PitchClass) -> u16 {
+        let folded = self.fold_to_octave().0;
+        let shift = root.value() as u32;
+        let octave_mask = (1u32 << SEMITONES_IN_OCTAVE) - 1;
+        let rotated = (folded << shift) | (folded >> (SEMITONES_IN_OCTAVE as u32 - shift));
+        (rotated & octave_mask) as u16
     }
 
-    /// Union two scale formulas (bitwise OR).
+    /// Check if the scale on `root` and `other` on `other_root` contain the same pitch classes.
+    ///
+    /// Roots and formulas may differ: relative keys and modes of the same
+    /// parent scale compare equal, as do enharmonic spellings of one root.
     ///
     /// # Examples
     /// ```
-    /// use musik_std::ScaleFormula;
+    /// use musik_std::{ScaleFormula, A, C, C_SHARP, D_FLAT, G};
     ///
     /// let major = ScaleFormula::major();
-    /// let minor = ScaleFormula::minor();
-    /// let combined = major.union(minor);
-    ///
-    /// // Combined should have both major and minor thirds
-    /// assert!(combined.contains_semitone(3)); // Minor 3rd
-    /// assert!(combined.contains_semitone(4)); // Major 3rd
+    /// assert!(major.same_pitches(C_SHARP, &major, D_FLAT));
+    /// assert!(major.same_pitches(C, &ScaleFormula::minor(), A));
+    /// assert!(!major.same_pitches(C, &major, G));
     /// ```
-    pub const fn union(self, other: ScaleFormula) -> ScaleFormula {
-        ScaleFormula(self.0 | other.0)
+    pub const fn same_pitches(
+        &self,
+        root: PitchClass,
+        other: &ScaleFormula,
+        other_root: PitchClass,
+    ) -> bool {
+        self.membership_mask(root) == other.membership_mask(other_root)
     }
 
-    /// Intersection of two scale formulas (bitwise AND).
+    /// Count how many chord notes fall outside the scale built on `root`.
+    ///
+    /// A diatonic chord scores 0; borrowed and secondary chords score 1 or more.
+    /// Repeated pitch classes are counted each time they appear.
     ///
     /// # Examples
     /// ```
-    /// use musik_std::ScaleFormula;
+    /// use musik_std::{ScaleFormula, A, C, D, E, F_SHARP, G};
     ///
     /// let major = ScaleFormula::major();
-    /// let minor = ScaleFormula::minor();
-    /// let common = major.intersection(minor);
-    ///
-    /// // Common notes: 1, 2, 4, 5
-    /// assert!(common.contains_semitone(0)); // Root
-    /// assert!(common.contains_semitone(2)); // Major 2nd
-    /// assert!(!common.contains_semitone(3)); // Minor 3rd (only in minor)
-    /// assert!(!common.contains_semitone(4)); // Major 3rd (only in major)
+    /// assert_eq!(major.chromaticism(C, &[C, E, G]), 0); // I in C major
+    /// assert_eq!(major.chromaticism(C, &[D, F_SHARP, A, C]), 1); // V7/V in C major
     /// ```
-    pub const fn intersection(self, other: ScaleFormula) -> ScaleFormula {
-        ScaleFormula(self.0 & other.0)
+    pub fn chromaticism(&self, root: PitchClass, chord_notes: &[PitchClass]) -> u8 {
+        chord_notes
+            .iter()
+            .filter(|&&note| !self.contains_pitch_class(root, note))
+            .count() as u8
     }
 
-    /// Complement of the scale formula (bitwise NOT, masked to first octave only).
+    /// Get the next scale tone above `from` in the scale built on `root`.
     ///
-    /// This only considers the first 12 semitones to maintain traditional scale complement behavior.
+    /// Pitch classes wrap around the octave, so stepping up from the 7th returns
+    /// the root. If `from` is not in the scale, the nearest scale tone above it is
+    /// returned. Returns `None` for an empty formula.
     ///
     /// # Examples
     /// ```
-    /// use musik_std::ScaleFormula;
+    /// use musik_std::{ScaleFormula, B, C, E, F, F_SHARP, G};
     ///
     /// let major = ScaleFormula::major();
-    /// let complement = major.complement();
-    ///
-    /// // Complement should contain all notes NOT in major scale
-    /// assert!(!complement.contains_semitone(0)); // Root not in complement
-    /// assert!(complement.contains_semitone(1)); // Minor 2nd in complement
+    /// assert_eq!(major.step_up(C, E), Some(F));
+    /// assert_eq!(major.step_up(C, B), Some(C));
+    /// assert_eq!(major.step_up(C, F_SHARP), Some(G)); // Not in the scale
     /// ```
-    pub const fn complement(self) -> ScaleFormula {
-        ScaleFormula((!self.0) & ((1u32 << SEMITONES_IN_OCTAVE) - 1)) // Mask to first octave
+    pub fn step_up(&self, root: PitchClass, from: PitchClass) -> Option<PitchClass> {
+        (1..=SEMITONES_IN_OCTAVE)
+            .map(|step| PitchClass::new(from.value() + step))
+            .find(|&pitch_class| self.contains_pitch_class(root, pitch_class))
     }
-}
-
-impl fmt::Display for ScaleFormula {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if self.is_empty() {
-            return write!(f, "Empty");
-        }
 
-        let semitones = self.semitones();
-        let note_names = [
-            "1", "♭2", "2", "♭3", "3", "4", "♭5", "5", "♭6", "6", "♭7", "7",
-        ];
-
-        let names: Vec<&str> = semitones.iter().map(|&s| note_names[s as usize]).collect();
+    /// Get the next scale tone below `from` in the scale built on `root`.
+    ///
+    /// Pitch classes wrap around the octave, so stepping down from the root returns
+    /// the 7th. If `from` is not in the scale, the nearest scale tone below it is
+    /// returned. Returns `None` for an empty formula.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{ScaleFormula, B, C, E, F, F_SHARP};
+    ///
+    /// let major = ScaleFormula::major();
+    /// assert_eq!(major.step_down(C, F), Some(E));
+    /// assert_eq!(major.step_down(C, C), Some(B));
+    /// assert_eq!(major.step_down(C, F_SHARP), Some(F)); // Not in the scale
+    /// ```
+    pub fn step_down(&self, root: PitchClass, from: PitchClass) -> Option<PitchClass> {
+        (1..=SEMITONES_IN_OCTAVE)
+            .map(|step| PitchClass::new(from.value() + SEMITONES_IN_OCTAVE - step))
+            .find(|&pitch_class| self.contains_pitch_class(root, pitch_class))
+    }
 
-        write!(f, "{}", names.join(", "))
+    /// Get the scale tones strictly between `from` and `to`, ascending, in the scale built on `root`.
+    ///
+    /// These are the passing tones of a scalar run. The run ascends around the
+    /// octave, so from G to C yields A and B; reverse the result of the
+    /// opposite run for a descending line. The endpoints do not have to be in
+    /// the scale, and equal endpoints have nothing between them.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{ScaleFormula, A, B, C, D, E, F, G};
+    ///
+    /// let major = ScaleFormula::major();
+    /// assert_eq!(major.passing_tones(C, C, G), vec![D, E, F]);
+    /// assert_eq!(major.passing_tones(C, G, C), vec![A, B]);
+    /// assert!(major.passing_tones(C, E, F).is_empty());
+    /// ```
+    pub fn passing_tones(
+        &self,
+        root: PitchClass,
+        from: PitchClass,
+        to: PitchClass,
+    ) -> Vec<PitchClass> {
+        let span = (to.value() + SEMITONES_IN_OCTAVE - from.value()) % SEMITONES_IN_OCTAVE;
+        (1..span)
+            .map(|step| PitchClass::new(from.value() + step))
+            .filter(|&pitch_class| self.contains_pitch_class(root, pitch_class))
+            .collect()
     }
-}
 
-impl fmt::Binary for ScaleFormula {
+    /// Transpose each pitch class by `steps` scale degrees in the scale built on `root`.
+    ///
+    /// Positive steps move up and negative steps move down, staying in the key.
+    /// A pitch class outside the scale lands on the nearest scale tone in the
+    /// direction of motion on its first step, as with [`ScaleFormula::step_up`].
+    /// An empty formula leaves the pitch classes unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{ScaleFormula, A, B, C, D, E, F, G};
+    ///
+    /// // Up a third within C major
+    /// let melody = [C, D, E, C];
+    /// let harmony = ScaleFormula::major().diatonic_transpose(C, &melody, 2);
+    /// assert_eq!(harmony, vec![E, F, G, E]);
+    ///
+    /// // Down a step wraps below the root
+    /// assert_eq!(ScaleFormula::major().diatonic_transpose(C, &[C, A], -1), vec![B, G]);
+    /// ```
+    pub fn diatonic_transpose(
+        &self,
+        root: PitchClass,
+        notes: &[PitchClass],
+        steps: i8,
+    ) -> Vec<PitchClass> {
+        notes
+            .iter()
+            .map(|&note| {
+                (0..steps.unsigned_abs())
+                    .try_fold(note, |current, _| {
+                        if steps > 0 {
+                            self.step_up(root, current)
+                        } else {
+                            self.step_down(root, current)
+                        }
+                    })
+                    .unwrap_or(note)
+            })
+            .collect()
+    }
+
+    /// Get the largest step between adjacent notes of the scale, in semitones.
+    ///
+    /// The formula is folded to a single octave first, and the step from the
+    /// highest note back up to the octave is included. Returns 0 for an empty
+    /// formula and 12 for a formula with a single pitch class.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ScaleFormula;
+    ///
+    /// assert_eq!(ScaleFormula::major().largest_gap(), 2);
+    /// assert_eq!(ScaleFormula::pentatonic_minor().largest_gap(), 3);
+    /// assert_eq!(ScaleFormula::harmonic_minor().largest_gap(), 3); // ♭6 to 7
+    /// ```
+    pub fn largest_gap(&self) -> u8 {
+        let folded = self.fold_to_octave();
+        let Some(first) = folded.iter_semitones().next() else {
+            return 0;
+        };
+
+        folded
+            .iter_semitones()
+            .chain(std::iter::once(first + SEMITONES_IN_OCTAVE))
+            .collect::<Vec<_>>()
+            .windows(2)
+            .map(|pair| pair[1] - pair[0])
+            .max()
+            .unwrap_or(SEMITONES_IN_OCTAVE)
+    }
+
+    /// Get the interval vector of the scale: how many pairs of notes span each
+    /// interval class from 1 to 6 semitones.
+    ///
+    /// The formula is folded to a single octave first. An interval and its
+    /// inversion fall in the same class, so a fifth counts as a fourth.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ScaleFormula;
+    ///
+    /// assert_eq!(ScaleFormula::major().interval_vector(), [2, 5, 4, 3, 6, 1]);
+    /// assert_eq!(ScaleFormula::whole_tone().interval_vector(), [0, 6, 0, 6, 0, 3]);
+    /// ```
+    pub fn interval_vector(&self) -> [u8; 6] {
+        let semitones = self.fold_to_octave().semitones();
+        let mut vector = [0; 6];
+        for (index, &low) in semitones.iter().enumerate() {
+            for &high in &semitones[index + 1..] {
+                let interval = high - low;
+                let class = interval.min(SEMITONES_IN_OCTAVE - interval);
+                vector[class as usize - 1] += 1;
+            }
+        }
+        vector
+    }
+
+    /// Check if the scale has a leading tone: a major 7th, a semitone below the octave.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ScaleFormula;
+    ///
+    /// assert!(ScaleFormula::major().has_leading_tone());
+    /// assert!(ScaleFormula::harmonic_minor().has_leading_tone());
+    /// assert!(!ScaleFormula::minor().has_leading_tone()); // Subtonic ♭7
+    /// ```
+    pub const fn has_leading_tone(&self) -> bool {
+        self.contains_semitone(SEMITONES_IN_OCTAVE - 1)
+    }
+
+    /// Get the smallest transposition, in semitones, that maps the scale onto itself.
+    ///
+    /// The formula is folded to a single octave first. Every scale repeats after
+    /// 12 semitones; symmetric scales repeat sooner. The empty and chromatic
+    /// formulas have a period of 1.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ScaleFormula;
+    ///
+    /// assert_eq!(ScaleFormula::major().symmetry_period(), 12);
+    /// assert_eq!(ScaleFormula::whole_tone().symmetry_period(), 2);
+    /// assert_eq!(ScaleFormula::octatonic_hw().symmetry_period(), 3);
+    /// ```
+    pub const fn symmetry_period(&self) -> u8 {
+        let octave_mask = (1u32 << SEMITONES_IN_OCTAVE) - 1;
+        let folded = self.fold_to_octave().0;
+
+        let mut period = 1;
+        while period < SEMITONES_IN_OCTAVE {
+            let rotated =
+                ((folded >> period) | (folded << (SEMITONES_IN_OCTAVE - period))) & octave_mask;
+            if rotated == folded {
+                return period;
+            }
+            period += 1;
+        }
+        SEMITONES_IN_OCTAVE
+    }
+
+    /// Check if the scale maps onto itself under some transposition smaller than an octave.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ScaleFormula;
+    ///
+    /// assert!(ScaleFormula::augmented_scale().is_symmetric());
+    /// assert!(!ScaleFormula::harmonic_minor().is_symmetric());
+    /// ```
+    pub const fn is_symmetric(&self) -> bool {
+        self.symmetry_period() < SEMITONES_IN_OCTAVE
+    }
+
+    /// Return the formula with one scale degree raised or lowered.
+    ///
+    /// `degree` is the 1-based position of a note in the scale, counted in ascending
+    /// order, so degree 4 of the major scale is its perfect 4th. The altered note stays
+    /// within its own octave, wrapping around if needed. An out-of-range degree leaves
+    /// the formula unchanged, and an alteration onto a note already in the scale merges
+    /// the two.
+    ///
+    /// This models modal interchange: raising the 4th of major gives Lydian, and lowering
+    /// the 6th gives the borrowed ♭6.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{DegreeAlteration, ScaleFormula};
+    ///
+    /// let major = ScaleFormula::major();
+    ///
+    /// let lydian = major.with_altered_degree(4, DegreeAlteration::Sharp);
+    /// assert_eq!(lydian, ScaleFormula::from_semitones(&[0, 2, 4, 6, 7, 9, 11]));
+    ///
+    /// let borrowed = major.with_altered_degree(6, DegreeAlteration::Flat);
+    /// assert!(borrowed.contains_semitone(8)); // ♭6
+    /// assert!(!borrowed.contains_semitone(9));
+    /// ```
+    pub fn with_altered_degree(&self, degree: u8, alteration: DegreeAlteration) -> ScaleFormula {
+        let Some(semitone) = degree
+            .checked_sub(1)
+            .and_then(|index| self.iter_semitones().nth(index as usize))
+        else {
+            return *self;
+        };
+
+        let octave_start = semitone - semitone % SEMITONES_IN_OCTAVE;
+        let altered = (semitone % SEMITONES_IN_OCTAVE) as i8 + alteration.semitone_offset();
+        let altered = octave_start + altered.rem_euclid(SEMITONES_IN_OCTAVE as i8) as u8;
+
+        ScaleFormula((self.0 & !(1 << semitone)) | (1 << altered))
+    }
+
+    /// Get the distinct pitch classes of the scale built on `root`, in ascending order.
+    ///
+    /// Degrees in the second octave fold onto the same pitch classes as the first.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{ScaleFormula, A, B, D, E, F_SHARP, G};
+    ///
+    /// let g_pentatonic = ScaleFormula::pentatonic_major().pitch_classes(G);
+    /// assert_eq!(g_pentatonic, vec![G, A, B, D, E]);
+    ///
+    /// let d_major = ScaleFormula::major_extended().pitch_classes(D);
+    /// assert_eq!(d_major.len(), 7);
+    /// assert!(d_major.contains(&F_SHARP));
+    /// ```
+    pub fn pitch_classes(&self, root: PitchClass) -> Vec<PitchClass> {
+        let mut result: Vec<PitchClass> = Vec::new();
+        for semitone in self.iter_semitones() {
+            let pitch_class = PitchClass::new(root.value() + semitone % SEMITONES_IN_OCTAVE);
+            if !result.contains(&pitch_class) {
+                result.push(pitch_class);
+            }
+        }
+        result
+    }
+
+    /// Pick a pitch class of the scale built on `root` uniformly at random.
+    ///
+    /// Returns `None` for an empty formula. Requires the `rand` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{ScaleFormula, C};
+    ///
+    /// let major = ScaleFormula::major();
+    /// let note = major.random_note(C, &mut rand::thread_rng()).unwrap();
+    /// assert!(major.contains_pitch_class(C, note));
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn random_note(&self, root: PitchClass, rng: &mut impl Rng) -> Option<PitchClass> {
+        let pitch_classes = self.pitch_classes(root);
+        if pitch_classes.is_empty() {
+            return None;
+        }
+        Some(pitch_classes[rng.gen_range(0..pitch_classes.len())])
+    }
+
+    /// Pick a pitch class of the scale built on `root` from a fixed seed.
+    ///
+    /// The same seed always yields the same pitch class, which makes generative
+    /// output reproducible. Returns `None` for an empty formula. Requires the
+    /// `rand` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{ScaleFormula, D};
+    ///
+    /// let minor = ScaleFormula::minor();
+    /// assert_eq!(minor.random_note_seeded(D, 7), minor.random_note_seeded(D, 7));
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn random_note_seeded(&self, root: PitchClass, seed: u64) -> Option<PitchClass> {
+        self.random_note(root, &mut StdRng::seed_from_u64(seed))
+    }
+
+    /// Generate a random diatonic triad progression in the key built on `root`.
+    ///
+    /// The progression walks a simple functional-harmony model: tonic chords
+    /// (I, vi, iii) move to subdominants (IV, ii) or dominants (V, vii°),
+    /// subdominants lean towards dominants, and dominants resolve to the tonic.
+    /// The progression starts on the tonic triad and, when it has more than one
+    /// chord, ends on it too. Returns an empty progression if the scale has no
+    /// tonic triad. Requires the `rand` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{ChordFormula, ScaleFormula, C};
+    ///
+    /// let major = ScaleFormula::major();
+    /// let progression = major.generate_progression(C, 8, &mut rand::thread_rng());
+    ///
+    /// assert_eq!(progression.len(), 8);
+    /// assert_eq!(progression[0], (C, ChordFormula::major_triad()));
+    /// assert_eq!(progression[7], (C, ChordFormula::major_triad()));
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn generate_progression(
+        &self,
+        root: PitchClass,
+        length: usize,
+        rng: &mut impl Rng,
+    ) -> Vec<(PitchClass, ChordFormula)> {
+        let triad = |degree: u8| {
+            let semitone = self.iter_semitones().nth(degree as usize - 1)?;
+            let quality = self.triad_quality(degree)?;
+            Some((PitchClass::new(root.value() + semitone), quality.triad()))
+        };
+
+        let Some(tonic) = triad(1) else {
+            return Vec::new();
+        };
+        if length == 0 {
+            return Vec::new();
+        }
+
+        let mut progression = vec![tonic];
+        let mut function = 0;
+        while progression.len() + 1 < length {
+            let weights = WeightedIndex::new(FUNCTION_WEIGHTS[function])
+                .expect("every function has a positive weight");
+            function = rng.sample(&weights);
+
+            let chords: Vec<(PitchClass, ChordFormula)> = FUNCTION_DEGREES[function]
+                .iter()
+                .filter_map(|&degree| triad(degree))
+                .collect();
+            if chords.is_empty() {
+                function = 0;
+                progression.push(tonic);
+            } else {
+                progression.push(chords[rng.gen_range(0..chords.len())]);
+            }
+        }
+
+        if length > 1 {
+            progression.push(tonic);
+        }
+        progression
+    }
+
+    /// Get the quality of the triad built on a scale degree.
+    ///
+    /// `degree` is the 1-based position of a note in the first octave of the scale.
+    /// The triad stacks the notes two and four positions above it, wrapping around the
+    /// octave. Returns `None` for an out-of-range degree or when the stacked notes do
+    /// not form a major, minor, diminished, or augmented triad.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{ChordQuality, ScaleFormula};
+    ///
+    /// let major = ScaleFormula::major();
+    /// assert_eq!(major.triad_quality(1), Some(ChordQuality::Major));
+    /// assert_eq!(major.triad_quality(2), Some(ChordQuality::Minor));
+    /// assert_eq!(major.triad_quality(7), Some(ChordQuality::Diminished));
+    ///
+    /// let harmonic_minor = ScaleFormula::harmonic_minor();
+    /// assert_eq!(harmonic_minor.triad_quality(3), Some(ChordQuality::Augmented));
+    /// ```
+    pub fn triad_quality(&self, degree: u8) -> Option<ChordQuality> {
+        let semitones: Vec<u8> = self
+            .iter_semitones()
+            .take_while(|&semitone| semitone < SEMITONES_IN_OCTAVE)
+            .collect();
+        let index = (degree as usize).checked_sub(1)?;
+        let root = *semitones.get(index)?;
+
+        let interval = |steps: usize| {
+            let note = semitones[(index + steps) % semitones.len()];
+            (note + SEMITONES_IN_OCTAVE - root) % SEMITONES_IN_OCTAVE
+        };
+
+        ChordQuality::from_intervals(interval(2), interval(4))
+    }
+
+    /// Get the mode of the scale starting on a given degree.
+    ///
+    /// `degree` is the 1-based position of a note in the first octave of the scale.
+    /// The scale is rotated so that note becomes the new root, keeping the result
+    /// within a single octave. Returns `None` for an out-of-range degree.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{ScaleFormula, D};
+    ///
+    /// let dorian = ScaleFormula::major().mode(2).unwrap();
+    /// assert_eq!(dorian, ScaleFormula::dorian());
+    /// assert_eq!(format!("{} {}", D, dorian.name().unwrap()), "D Dorian");
+    ///
+    /// assert_eq!(ScaleFormula::major().mode(8), None);
+    /// ```
+    pub fn mode(&self, degree: u8) -> Option<ScaleFormula> {
+        let index = (degree as usize).checked_sub(1)?;
+        let start = self
+            .iter_semitones()
+            .take_while(|&semitone| semitone < SEMITONES_IN_OCTAVE)
+            .nth(index)?;
+
+        let rotated = self
+            .iter_semitones()
+            .take_while(|&semitone| semitone < SEMITONES_IN_OCTAVE)
+            .fold(0, |bits, semitone| {
+                bits | 1 << ((semitone + SEMITONES_IN_OCTAVE - start) % SEMITONES_IN_OCTAVE)
+            });
+
+        Some(ScaleFormula(rotated))
+    }
+
+    /// Get where an active degree of a seven-note scale tends to resolve.
+    ///
+    /// The stable degrees are those of the tonic triad (1, 3, and 5). Any other
+    /// degree resolves by step to a neighbouring stable degree, taking the
+    /// smaller step when both neighbours are stable and moving down on a tie.
+    /// In the major scale the leading tone rises to the tonic, and the 4th and
+    /// 6th fall to the 3rd and 5th. Only the first octave is considered. Returns
+    /// `None` for a stable degree, a degree outside the scale, or a formula
+    /// without a root or without exactly seven notes.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{Contour, ScaleFormula, Tendency};
+    ///
+    /// let major = ScaleFormula::major();
+    /// assert_eq!(major.tendency(7), Some(Tendency::new(1, Contour::Up)));
+    /// assert_eq!(major.tendency(4), Some(Tendency::new(3, Contour::Down)));
+    /// assert_eq!(major.tendency(1), None); // Already stable
+    /// ```
+    pub fn tendency(&self, degree: u8) -> Option<Tendency> {
+        if !self.has_root() || STABLE_DEGREES.contains(&degree) {
+            return None;
+        }
+
+        let semitones: Vec<u8> = self
+            .iter_semitones()
+            .take_while(|&semitone| semitone < SEMITONES_IN_OCTAVE)
+            .collect();
+        if semitones.len() != HEPTATONIC_NOTE_COUNT {
+            return None;
+        }
+        let index = (degree as usize).checked_sub(1)?;
+        let semitone = *semitones.get(index)?;
+
+        // Degree numbers and step sizes of the neighbours, wrapping up to the octave
+        let below = index
+            .checked_sub(1)
+            .map(|below| (below as u8 + 1, semitone - semitones[below]));
+        let above = match semitones.get(index + 1) {
+            Some(&next) => (degree + 1, next - semitone),
+            None => (1, SEMITONES_IN_OCTAVE - semitone),
+        };
+
+        let below = below.filter(|(target, _)| STABLE_DEGREES.contains(target));
+        let above = Some(above).filter(|(target, _)| STABLE_DEGREES.contains(target));
+
+        match (below, above) {
+            (Some((target, down)), Some((_, up))) if down <= up => {
+                Some(Tendency::new(target, Contour::Down))
+            }
+            (_, Some((target, _))) => Some(Tendency::new(target, Contour::Up)),
+            (Some((target, _)), None) => Some(Tendency::new(target, Contour::Down)),
+            (None, None) => None,
+        }
+    }
+
+    /// Check if this formula is one of the modes of `other`.
+    ///
+    /// The formulas are compared as single-octave pitch-class sets: this formula
+    /// is folded with [`fold_to_octave`](Self::fold_to_octave), and the modes of
+    /// `other` are built from its first octave. A formula is a rotation of itself,
+    /// as its first mode.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ScaleFormula;
+    ///
+    /// assert!(ScaleFormula::dorian().is_rotation_of(&ScaleFormula::major()));
+    /// assert!(ScaleFormula::pentatonic_minor().is_rotation_of(&ScaleFormula::pentatonic_major()));
+    /// assert!(!ScaleFormula::harmonic_minor().is_rotation_of(&ScaleFormula::major()));
+    /// assert!(ScaleFormula::major_extended().is_rotation_of(&ScaleFormula::major()));
+    /// ```
+    pub fn is_rotation_of(&self, other: &ScaleFormula) -> bool {
+        let folded = self.fold_to_octave();
+        (1..=other.note_count()).any(|degree| other.mode(degree) == Some(folded))
+    }
+
+    /// Check if the scale is diatonic: one of the seven modes of the major scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ScaleFormula;
+    ///
+    /// assert!(ScaleFormula::dorian().is_diatonic());
+    /// assert!(!ScaleFormula::whole_tone().is_diatonic());
+    /// assert!(!ScaleFormula::blues().is_diatonic());
+    /// ```
+    pub fn is_diatonic(&self) -> bool {
+        self.is_rotation_of(&ScaleFormula::major())
+    }
+
+    /// Get the root of the major scale that this scale, built on `root`, is a mode of.
+    ///
+    /// Extended formulas are folded to one octave first, as in
+    /// [`is_diatonic`](Self::is_diatonic). Returns `None` if the scale is not
+    /// diatonic.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{ScaleFormula, C, D, E_FLAT};
+    ///
+    /// assert_eq!(ScaleFormula::dorian().parent_major(D), Some(C));
+    /// assert_eq!(ScaleFormula::minor().parent_major(C), Some(E_FLAT));
+    /// assert_eq!(ScaleFormula::harmonic_minor().parent_major(C), None);
+    /// ```
+    pub fn parent_major(&self, root: PitchClass) -> Option<PitchClass> {
+        let major = ScaleFormula::major();
+        let folded = self.fold_to_octave();
+        let offset = major
+            .iter_semitones()
+            .zip(1..)
+            .find(|&(_, degree)| major.mode(degree) == Some(folded))
+            .map(|(semitone, _)| semitone)?;
+
+        Some(PitchClass::new(root.value() + SEMITONES_IN_OCTAVE - offset))
+    }
+
+    /// Get the movable-do solfège syllable of `note` in the scale built on `root`.
+    ///
+    /// Scale tones are named after their spelling in the scale, so raised
+    /// degrees take raised syllables (di, ri, fi, si, li) and lowered degrees
+    /// take lowered ones (ra, me, se, le, te). Notes outside the scale get
+    /// their chromatic syllable: lowered except for the raised fourth (fi).
+    /// Returns `None` for an empty formula, which has no tonic.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{ScaleFormula, C, E, E_FLAT, F_SHARP, G, G_FLAT};
+    ///
+    /// let major = ScaleFormula::major();
+    /// assert_eq!(major.solfege(C, C), Some("do"));
+    /// assert_eq!(major.solfege(C, E), Some("mi"));
+    /// assert_eq!(major.solfege(C, F_SHARP), Some("fi")); // Not in the scale
+    /// assert_eq!(major.solfege(G, F_SHARP), Some("ti"));
+    /// assert_eq!(ScaleFormula::minor().solfege(C, E_FLAT), Some("me"));
+    ///
+    /// // The tritone follows the scale's spelling
+    /// assert_eq!(ScaleFormula::lydian().solfege(C, F_SHARP), Some("fi"));
+    /// assert_eq!(ScaleFormula::locrian().solfege(C, G_FLAT), Some("se"));
+    /// ```
+    pub fn solfege(&self, root: PitchClass, note: PitchClass) -> Option<&'static str> {
+        if self.note_count() == 0 {
+            return None;
+        }
+
+        let interval = (note.value() + SEMITONES_IN_OCTAVE - root.value()) % SEMITONES_IN_OCTAVE;
+        if !self.contains_pitch_class(root, note) {
+            return Some(SOLFEGE_SYLLABLES[interval as usize]);
+        }
+
+        let syllable = match self.degree_spelling(interval) {
+            (DegreeAlteration::None, degree) => NATURAL_SYLLABLES[degree as usize - 1],
+            (DegreeAlteration::Sharp, 1) => "di",
+            (DegreeAlteration::Sharp, 2) => "ri",
+            (DegreeAlteration::Sharp, 4) => "fi",
+            (DegreeAlteration::Sharp, 5) => "si",
+            (DegreeAlteration::Sharp, 6) => "li",
+            (DegreeAlteration::Flat, 2) => "ra",
+            (DegreeAlteration::Flat, 3) => "me",
+            (DegreeAlteration::Flat, 5) => "se",
+            (DegreeAlteration::Flat, 6) => "le",
+            (DegreeAlteration::Flat, 7) => "te",
+            _ => SOLFEGE_SYLLABLES[interval as usize],
+        };
+        Some(syllable)
+    }
+
+    /// Get the name of a recognized scale formula.
+    ///
+    /// Recognizes the single-octave formulas with a named constructor, including
+    /// every mode of the major scale. Returns `None` for any other formula.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ScaleFormula;
+    ///
+    /// assert_eq!(ScaleFormula::major().name(), Some("Major"));
+    /// assert_eq!(ScaleFormula::major().mode(5).unwrap().name(), Some("Mixolydian"));
+    /// assert_eq!(ScaleFormula::from_semitones(&[0, 1, 2]).name(), None);
+    /// ```
+    pub fn name(&self) -> Option<&'static str> {
+        NAMED_SCALES
+            .iter()
+            .find(|(formula, _)| formula == self)
+            .map(|&(_, name)| name)
+    }
+
+    /// Look up a named scale formula by its name, ignoring ASCII case.
+    ///
+    /// This is the inverse of [`name`](Self::name). Returns `None` for an
+    /// unknown name.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ScaleFormula;
+    ///
+    /// assert_eq!(ScaleFormula::from_name("Dorian"), Some(ScaleFormula::dorian()));
+    /// assert_eq!(ScaleFormula::from_name("harmonic minor"), Some(ScaleFormula::harmonic_minor()));
+    /// assert_eq!(ScaleFormula::from_name("Bebop"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<ScaleFormula> {
+        NAMED_SCALES
+            .iter()
+            .find(|(_, scale_name)| scale_name.eq_ignore_ascii_case(name))
+            .map(|&(formula, _)| formula)
+    }
+
+    /// Iterate over every named scale formula, in a stable order.
+    ///
+    /// Yields each formula recognized by [`name`](Self::name): the seven modes of
+    /// the major scale, harmonic minor, altered, the pentatonics, blues, the symmetric
+    /// scales (whole tone, octatonic, augmented), and chromatic.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ScaleFormula;
+    ///
+    /// assert_eq!(ScaleFormula::all_named().count(), 17);
+    /// assert!(ScaleFormula::all_named().all(|formula| formula.name().is_some()));
+    /// ```
+    pub fn all_named() -> impl Iterator<Item = ScaleFormula> {
+        NAMED_SCALES.into_iter().map(|(formula, _)| formula)
+    }
+
+    /// Get the scale-degree label of each note, with alterations relative to the major scale.
+    ///
+    /// Altered notes are spelled as flats by default. When the flat would share its
+    /// degree number with a natural note of the scale (in either octave) and the
+    /// degree below is free, the note is spelled as a sharp instead, so Lydian reads
+    /// `♯4` rather than `♭5`. Notes in the second octave are labeled as compound
+    /// degrees (9, 11, 13, ...).
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ScaleFormula;
+    ///
+    /// assert_eq!(
+    ///     ScaleFormula::minor().degree_labels(),
+    ///     vec!["1", "2", "♭3", "4", "5", "♭6", "♭7"]
+    /// );
+    /// assert_eq!(
+    ///     ScaleFormula::lydian().degree_labels(),
+    ///     vec!["1", "2", "3", "♯4", "5", "6", "7"]
+    /// );
+    /// ```
+    pub fn degree_labels(&self) -> Vec<String> {
+        self.iter_semitones()
+            .map(|semitone| {
+                let (alteration, degree) = self.degree_spelling(semitone);
+                let octave = semitone / SEMITONES_IN_OCTAVE;
+                format!("{}{}", alteration.symbol(), degree + 7 * octave)
+            })
+            .collect()
+    }
+
+    /// Spell the notes of the scale built on `root`, separated by spaces.
+    ///
+    /// Each note takes the letter of its scale degree (see
+    /// [`degree_labels`](Self::degree_labels)), so a seven-note scale uses every
+    /// letter once and F major is spelled with B♭ rather than A♯. A black-key root
+    /// is spelled as a flat. Accidentals are written as `#` and `b`.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{ScaleFormula, C, F};
+    ///
+    /// assert_eq!(ScaleFormula::major().display_notes(C), "C D E F G A B");
+    /// assert_eq!(ScaleFormula::major().display_notes(F), "F G A Bb C D E");
+    /// assert_eq!(ScaleFormula::blues().display_notes(C), "C Eb F Gb G Bb");
+    /// ```
+    pub fn display_notes(&self, root: PitchClass) -> String {
+        self.spelled_from(root, true).join(" ")
+    }
+
+    /// Spell each note of the scale built on `root`, choosing how to spell a black-key root.
+    ///
+    /// A black-key root is spelled as a flat when `prefer_flats` is set and as a
+    /// sharp otherwise, and the rest of the scale follows from the root's letter
+    /// as in [`display_notes`](Self::display_notes). A natural root is spelled
+    /// the same either way, so F major always has B♭ and G major always has F♯.
+    /// Accidentals are written as `#` and `b`.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{ScaleFormula, F_SHARP, G};
+    ///
+    /// let major = ScaleFormula::major();
+    /// assert_eq!(major.spelled_from(F_SHARP, true), ["Gb", "Ab", "Bb", "Cb", "Db", "Eb", "F"]);
+    /// assert_eq!(major.spelled_from(F_SHARP, false), ["F#", "G#", "A#", "B", "C#", "D#", "E#"]);
+    /// assert_eq!(major.spelled_from(G, false), ["G", "A", "B", "C", "D", "E", "F#"]);
+    /// ```
+    pub fn spelled_from(&self, root: PitchClass, prefer_flats: bool) -> Vec<String> {
+        self.spelled_notes(root, prefer_flats)
+            .map(|(pitch_class, letter)| {
+                let offset = signed_distance(NATURALS[letter], pitch_class.value());
+                format!("{}{}", LETTERS[letter], accidentals(offset))
+            })
+            .collect()
+    }
+
+    /// Label every note of the scale built on `root` between `low` and `high`, inclusive.
+    ///
+    /// Notes are spelled as in [`display_notes`](Self::display_notes) and
+    /// followed by their octave, with middle C as C4. The octave follows the
+    /// letter, so the C♭ that sounds as B3 is labelled `Cb4`. Returns the notes
+    /// from lowest to highest, or nothing if `low` is above `high`.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{Note, ScaleFormula, C, F};
+    ///
+    /// let labels = ScaleFormula::major().labels_in_range(C, Note::new(60), Note::new(64));
+    /// assert_eq!(
+    ///     labels,
+    ///     vec![
+    ///         (Note::new(60), "C4".to_string()),
+    ///         (Note::new(62), "D4".to_string()),
+    ///         (Note::new(64), "E4".to_string()),
+    ///     ]
+    /// );
+    ///
+    /// let labels = ScaleFormula::major().labels_in_range(F, Note::new(69), Note::new(71));
+    /// assert_eq!(labels[1], (Note::new(70), "Bb4".to_string()));
+    /// ```
+    pub fn labels_in_range(&self, root: PitchClass, low: Note, high: Note) -> Vec<(Note, String)> {
+        let spellings: Vec<(PitchClass, usize)> = self.spelled_notes(root, true).collect();
+
+        (low.semitone()..=high.semitone())
+            .map(Note::new)
+            .filter_map(|note| {
+                let &(pitch_class, letter) = spellings
+                    .iter()
+                    .find(|&&(pitch_class, _)| pitch_class == note.pitch_class())?;
+                let offset = signed_distance(NATURALS[letter], pitch_class.value());
+
+                // The octave of the natural letter, so B#3 and Cb4 keep their letter's octave
+                let natural = note.semitone() as i16 - offset as i16;
+                let octave = natural.div_euclid(SEMITONES_IN_OCTAVE as i16) - 1;
+
+                let label = format!("{}{}{}", LETTERS[letter], accidentals(offset), octave);
+                Some((note, label))
+            })
+            .collect()
+    }
+
+    /// Name the interval between two notes of the scale built on `root`.
+    ///
+    /// Both notes are spelled as in [`display_notes`](Self::display_notes), and the
+    /// interval is measured upwards from `from` to `to` within an octave. Its number
+    /// comes from the letter distance and its quality from the semitone count, so the
+    /// same six semitones are an augmented fourth in Lydian (C to F♯) and a diminished
+    /// fifth in Locrian (C to G♭). Returns `None` if either note is not in the scale
+    /// or the quality is beyond doubly augmented or diminished.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{ScaleFormula, C, E, F_SHARP, G, G_FLAT};
+    ///
+    /// let major = ScaleFormula::major();
+    /// assert_eq!(major.interval_quality(C, C, E).as_deref(), Some("major third"));
+    /// assert_eq!(major.interval_quality(C, C, G).as_deref(), Some("perfect fifth"));
+    ///
+    /// let lydian = ScaleFormula::lydian();
+    /// assert_eq!(lydian.interval_quality(C, C, F_SHARP).as_deref(), Some("augmented fourth"));
+    ///
+    /// let locrian = ScaleFormula::locrian();
+    /// assert_eq!(locrian.interval_quality(C, C, G_FLAT).as_deref(), Some("diminished fifth"));
+    /// ```
+    pub fn interval_quality(
+        &self,
+        root: PitchClass,
+        from: PitchClass,
+        to: PitchClass,
+    ) -> Option<String> {
+        let letter_of = |target: PitchClass| {
+            self.spelled_notes(root, true)
+                .find(|&(pitch_class, _)| pitch_class == target)
+                .map(|(_, letter)| letter)
+        };
+        let from_letter = letter_of(from)?;
+        let to_letter = letter_of(to)?;
+
+        let number = (to_letter + LETTERS.len() - from_letter) % LETTERS.len();
+        let semitones = (to.value() + SEMITONES_IN_OCTAVE - from.value()) % SEMITONES_IN_OCTAVE;
+        let deviation = signed_distance(INTERVAL_REFERENCES[number], semitones);
+
+        let is_perfect = matches!(number, 0 | 3 | 4);
+        let quality = match (is_perfect, deviation) {
+            (true, 0) => "perfect",
+            (false, 0) => "major",
+            (false, -1) => "minor",
+            (true, -1) | (false, -2) => "diminished",
+            (true, -2) | (false, -3) => "doubly diminished",
+            (_, 1) => "augmented",
+            (_, 2) => "doubly augmented",
+            _ => return None,
+        };
+
+        Some(format!("{} {}", quality, INTERVAL_NUMBERS[number]))
+    }
+
+    /// Spell each note of the scale built on `root` as a pitch class and a letter index.
+    ///
+    /// Each note takes the letter of its scale degree. Natural roots keep their
+    /// letter, and black-key roots take the letter above (spelled as a flat) when
+    /// `prefer_flats` is set, or the letter below (spelled as a sharp) otherwise.
+    fn spelled_notes(
+        &self,
+        root: PitchClass,
+        prefer_flats: bool,
+    ) -> impl Iterator<Item = (PitchClass, usize)> + '_ {
+        let root_letter = if prefer_flats {
+            NATURALS.iter().position(|&natural| natural >= root.value())
+        } else {
+            NATURALS
+                .iter()
+                .rposition(|&natural| natural <= root.value())
+        }
+        .unwrap_or(0);
+
+        self.iter_semitones().map(move |semitone| {
+            let (_, degree) = self.degree_spelling(semitone);
+            let letter = (root_letter + degree as usize - 1) % LETTERS.len();
+            let pitch_class = PitchClass::new(root.value() + semitone % SEMITONES_IN_OCTAVE);
+            (pitch_class, letter)
+        })
+    }
+
+    /// Spell a semitone of the scale as an alteration and a degree (1-7) of the major scale.
+    fn degree_spelling(&self, semitone: u8) -> (DegreeAlteration, u8) {
+        let has_natural = |degree: u8| {
+            DEGREE_SPELLINGS
+                .iter()
+                .position(|&spelling| spelling == (DegreeAlteration::None, degree))
+                .is_some_and(|step| {
+                    let step = step as u8;
+                    self.contains_semitone(step)
+                        || self.contains_semitone(step + SEMITONES_IN_OCTAVE)
+                })
+        };
+
+        let (alteration, degree) = DEGREE_SPELLINGS[(semitone % SEMITONES_IN_OCTAVE) as usize];
+        if alteration.is_flat() && has_natural(degree) && !has_natural(degree - 1) {
+            (DegreeAlteration::Sharp, degree - 1)
+        } else {
+            (alteration, degree)
+        }
+    }
+
+    /// Get the internal bit representation.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ScaleFormula;
+    ///
+    /// let major = ScaleFormula::major();
+    /// let bits = major.bits();
+    /// assert_eq!(bits, 0b101010110101);
+    ///
+    /// // Extended formulas can have bits in second octave
+    /// let extended = ScaleFormula::major_extended();
+    /// assert!(extended.bits() > 0xFFF); // Uses more than 12 bits
+    /// ```
+    pub const fn bits(&self) -> u32 {
+        self.0
+    }
+
+    /// Check if the scale formula is empty (no notes).
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ScaleFormula;
+    ///
+    /// assert!(ScaleFormula::empty().is_empty());
+    /// assert!(!ScaleFormula::major().is_empty());
+    /// ```
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Check if the scale contains the root note (semitone 0).
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ScaleFormula;
+    ///
+    /// assert!(ScaleFormula::major().has_root());
+    /// assert!(!ScaleFormula::empty().has_root());
+    /// ```
+    pub const fn has_root(&self) -> bool {
+        self.contains_semitone(0)
+    }
+
+    /// Union two scale formulas (bitwise OR).
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ScaleFormula;
+    ///
+    /// let major = ScaleFormula::major();
+    /// let minor = ScaleFormula::minor();
+    /// let combined = major.union(minor);
+    ///
+    /// // Combined should have both major and minor thirds
+    /// assert!(combined.contains_semitone(3)); // Minor 3rd
+    /// assert!(combined.contains_semitone(4)); // Major 3rd
+    /// ```
+    pub const fn union(self, other: ScaleFormula) -> ScaleFormula {
+        ScaleFormula(self.0 | other.0)
+    }
+
+    /// Intersection of two scale formulas (bitwise AND).
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ScaleFormula;
+    ///
+    /// let major = ScaleFormula::major();
+    /// let minor = ScaleFormula::minor();
+    /// let common = major.intersection(minor);
+    ///
+    /// // Common notes: 1, 2, 4, 5
+    /// assert!(common.contains_semitone(0)); // Root
+    /// assert!(common.contains_semitone(2)); // Major 2nd
+    /// assert!(!common.contains_semitone(3)); // Minor 3rd (only in minor)
+    /// assert!(!common.contains_semitone(4)); // Major 3rd (only in major)
+    /// ```
+    pub const fn intersection(self, other: ScaleFormula) -> ScaleFormula {
+        ScaleFormula(self.0 & other.0)
+    }
+
+    /// Jaccard similarity between two scale formulas over the first octave.
+    ///
+    /// Computed as `|A ∩ B| / |A ∪ B|`: 1.0 for identical formulas, 0.0 for
+    /// disjoint ones. Two empty formulas are considered identical.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ScaleFormula;
+    ///
+    /// let major = ScaleFormula::major();
+    /// let minor = ScaleFormula::minor();
+    ///
+    /// assert_eq!(major.jaccard_similarity(&major), 1.0);
+    /// assert_eq!(major.jaccard_similarity(&minor), 4.0 / 10.0);
+    /// ```
+    pub fn jaccard_similarity(&self, other: &ScaleFormula) -> f64 {
+        let first_octave = ScaleFormula::chromatic();
+        let a = self.intersection(first_octave);
+        let b = other.intersection(first_octave);
+
+        let union = a.union(b).note_count();
+        if union == 0 {
+            return 1.0;
+        }
+
+        a.intersection(b).note_count() as f64 / union as f64
+    }
+
+    /// Complement of the scale formula (bitwise NOT, masked to first octave only).
+    ///
+    /// This only considers the first 12 semitones to maintain traditional scale complement behavior.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ScaleFormula;
+    ///
+    /// let major = ScaleFormula::major();
+    /// let complement = major.complement();
+    ///
+    /// // Complement should contain all notes NOT in major scale
+    /// assert!(!complement.contains_semitone(0)); // Root not in complement
+    /// assert!(complement.contains_semitone(1)); // Minor 2nd in complement
+    /// ```
+    pub const fn complement(self) -> ScaleFormula {
+        ScaleFormula((!self.0) & ((1u32 << SEMITONES_IN_OCTAVE) - 1)) // Mask to first octave
+    }
+
+    /// Complement of the scale formula across both octaves (bitwise NOT, masked to 24 bits).
+    ///
+    /// Unlike [`complement`](Self::complement), this keeps the second octave so that
+    /// extended formulas can be used in set operations.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ScaleFormula;
+    ///
+    /// let major_extended = ScaleFormula::major_extended();
+    /// let complement = major_extended.complement_extended();
+    ///
+    /// assert_eq!(complement.note_count(), 24 - major_extended.note_count());
+    /// assert!(complement.contains_semitone(1)); // ♭2
+    /// assert!(complement.contains_semitone(13)); // ♭9
+    /// assert!(!complement.contains_semitone(14)); // 9th is in the scale
+    /// ```
+    pub const fn complement_extended(self) -> ScaleFormula {
+        ScaleFormula((!self.0) & ((1u32 << (2 * SEMITONES_IN_OCTAVE)) - 1)) // Mask to both octaves
+    }
+
+    /// Fold the second octave down into the first, giving the single-octave pitch-class set.
+    ///
+    /// Each extension (9th, 11th, 13th, ...) is merged with its first-octave
+    /// counterpart (2nd, 4th, 6th, ...). This undoes the extended constructors.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::ScaleFormula;
+    ///
+    /// assert_eq!(ScaleFormula::major_extended().fold_to_octave(), ScaleFormula::major());
+    ///
+    /// // ♭9 folds down to ♭2
+    /// let folded = ScaleFormula::from_semitones(&[0, 4, 7, 13]).fold_to_octave();
+    /// assert_eq!(folded, ScaleFormula::from_semitones(&[0, 1, 4, 7]));
+    /// ```
+    pub const fn fold_to_octave(self) -> ScaleFormula {
+        let octave_mask = (1u32 << SEMITONES_IN_OCTAVE) - 1;
+        ScaleFormula((self.0 | (self.0 >> SEMITONES_IN_OCTAVE)) & octave_mask)
+    }
+}
+
+/// Accidentals for a signed distance from a natural letter: sharps (`#`) up and flats (`b`) down.
+fn accidentals(offset: i8) -> String {
+    match offset {
+        offset if offset > 0 => "#".repeat(offset as usize),
+        offset => "b".repeat(offset.unsigned_abs() as usize),
+    }
+}
+
+/// Signed distance from `from` up or down to `to` around the octave, in the range -6..=5.
+fn signed_distance(from: u8, to: u8) -> i8 {
+    (to as i8 - from as i8 + 18) % SEMITONES_IN_OCTAVE as i8 - 6
+}
+
+impl fmt::Display for ScaleFormula {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "Empty");
+        }
+
+        let semitones = self.semitones();
+        let note_names = [
+            "1", "♭2", "2", "♭3", "3", "4", "♭5", "5", "♭6", "6", "♭7", "7",
+        ];
+
+        let names: Vec<&str> = semitones.iter().map(|&s| note_names[s as usize]).collect();
+
+        write!(f, "{}", names.join(", "))
+    }
+}
+
+impl fmt::Binary for ScaleFormula {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{:012b}", self.0)
     }
@@ -644,6 +2116,59 @@ mod tests {
         assert!(blues.contains_semitone(6)); // ♭5 (tritone)
     }
 
+    #[test]
+    fn test_altered_scale() {
+        let altered = ScaleFormula::altered();
+        assert_eq!(altered.semitones(), vec![0, 1, 3, 4, 6, 8, 10]);
+        assert_eq!(altered.note_count(), 7);
+        assert_eq!(altered.name(), Some("Altered"));
+        assert!(!altered.is_symmetric());
+    }
+
+    #[test]
+    fn test_symmetric_scales() {
+        let wh = ScaleFormula::octatonic_wh();
+        assert_eq!(wh.semitones(), vec![0, 2, 3, 5, 6, 8, 9, 11]);
+        assert_eq!(wh.note_count(), 8);
+        assert_eq!(wh.symmetry_period(), 3);
+        assert_eq!(wh.name(), Some("Octatonic Whole-Half"));
+
+        let hw = ScaleFormula::octatonic_hw();
+        assert_eq!(hw.semitones(), vec![0, 1, 3, 4, 6, 7, 9, 10]);
+        assert_eq!(hw.note_count(), 8);
+        assert_eq!(hw.symmetry_period(), 3);
+        assert_eq!(hw.mode(2), Some(wh));
+        assert_eq!(wh.mode(2), Some(hw));
+
+        let augmented = ScaleFormula::augmented_scale();
+        assert_eq!(augmented.semitones(), vec![0, 3, 4, 7, 8, 11]);
+        assert_eq!(augmented.symmetry_period(), 4);
+        assert_eq!(ScaleFormula::from_name("augmented"), Some(augmented));
+    }
+
+    #[test]
+    fn test_symmetry_period() {
+        assert_eq!(ScaleFormula::whole_tone().symmetry_period(), 2);
+        assert_eq!(ScaleFormula::chromatic().symmetry_period(), 1);
+        assert_eq!(ScaleFormula::empty().symmetry_period(), 1);
+        assert_eq!(ScaleFormula::from_semitones(&[0, 6]).symmetry_period(), 6);
+
+        for formula in [
+            ScaleFormula::major(),
+            ScaleFormula::harmonic_minor(),
+            ScaleFormula::pentatonic_major(),
+            ScaleFormula::blues(),
+        ] {
+            assert_eq!(formula.symmetry_period(), 12);
+            assert!(!formula.is_symmetric());
+        }
+
+        // The second octave is folded down first
+        let extended = ScaleFormula::from_semitones(&[0, 6, 18]);
+        assert_eq!(extended.symmetry_period(), 6);
+        assert!(extended.is_symmetric());
+    }
+
     #[test]
     fn test_from_semitones() {
         let custom = ScaleFormula::from_semitones(&[0, 2, 4, 7, 9]);
@@ -659,6 +2184,29 @@ mod tests {
         assert_eq!(with_invalid.semitones(), vec![0, 2]);
     }
 
+    #[test]
+    fn test_with_and_without_semitone() {
+        let built = [0, 2, 4, 5, 7, 9, 11]
+            .into_iter()
+            .fold(ScaleFormula::empty(), ScaleFormula::with_semitone);
+        assert_eq!(built, ScaleFormula::major());
+
+        // Adding an existing semitone is a no-op
+        assert_eq!(built.with_semitone(4), built);
+
+        let without_seventh = built.without_semitone(11);
+        assert_eq!(without_seventh.note_count(), 6);
+        assert!(!without_seventh.contains_semitone(11));
+
+        // Removing a missing semitone is a no-op
+        assert_eq!(without_seventh.without_semitone(11), without_seventh);
+
+        // Second octave is supported, anything beyond is ignored
+        assert!(built.with_semitone(23).contains_semitone(23));
+        assert_eq!(built.with_semitone(24), built);
+        assert_eq!(built.without_semitone(24), built);
+    }
+
     #[test]
     fn test_bitwise_operations() {
         let major = ScaleFormula::major();
@@ -683,6 +2231,244 @@ mod tests {
         assert!(complement.contains_semitone(3)); // Minor 3rd in complement
     }
 
+    #[test]
+    fn test_labels_in_range() {
+        use crate::pitch_class::{C, F_SHARP};
+
+        let major = ScaleFormula::major();
+        let labels = major.labels_in_range(C, Note::new(60), Note::new(72));
+        let names: Vec<&str> = labels.iter().map(|(_, label)| label.as_str()).collect();
+        assert_eq!(names, ["C4", "D4", "E4", "F4", "G4", "A4", "B4", "C5"]);
+        let notes: Vec<u8> = labels.iter().map(|(note, _)| note.semitone()).collect();
+        assert_eq!(notes, [60, 62, 64, 65, 67, 69, 71, 72]);
+
+        // Black keys and the letter-based octave of C♭
+        let g_flat = major.labels_in_range(F_SHARP, Note::new(58), Note::new(61));
+        assert_eq!(
+            g_flat,
+            vec![
+                (Note::new(58), "Bb3".to_string()),
+                (Note::new(59), "Cb4".to_string()),
+                (Note::new(61), "Db4".to_string()),
+            ]
+        );
+
+        // The lowest octave is -1
+        assert_eq!(
+            major.labels_in_range(C, Note::new(0), Note::new(2)),
+            vec![
+                (Note::new(0), "C-1".to_string()),
+                (Note::new(2), "D-1".to_string())
+            ]
+        );
+
+        assert!(major
+            .labels_in_range(C, Note::new(72), Note::new(60))
+            .is_empty());
+        assert!(ScaleFormula::empty()
+            .labels_in_range(C, Note::new(0), Note::new(127))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_spelled_from() {
+        use crate::pitch_class::{A, B_FLAT, C, C_SHARP, F, G, G_FLAT};
+
+        let major = ScaleFormula::major();
+
+        // Natural roots spell the same either way
+        for prefer_flats in [true, false] {
+            assert!(major
+                .spelled_from(F, prefer_flats)
+                .contains(&"Bb".to_string()));
+            assert!(major
+                .spelled_from(G, prefer_flats)
+                .contains(&"F#".to_string()));
+            assert_eq!(
+                major.spelled_from(A, prefer_flats),
+                ["A", "B", "C#", "D", "E", "F#", "G#"]
+            );
+        }
+
+        // Black-key roots follow the preference
+        assert_eq!(
+            major.spelled_from(G_FLAT, true),
+            ["Gb", "Ab", "Bb", "Cb", "Db", "Eb", "F"]
+        );
+        assert_eq!(
+            major.spelled_from(B_FLAT, false),
+            ["A#", "B#", "C##", "D#", "E#", "F##", "G##"]
+        );
+        assert_eq!(
+            ScaleFormula::minor().spelled_from(C_SHARP, false),
+            ["C#", "D#", "E", "F#", "G#", "A", "B"]
+        );
+        assert_eq!(
+            ScaleFormula::minor().spelled_from(C_SHARP, true),
+            ["Db", "Eb", "Fb", "Gb", "Ab", "Bbb", "Cb"]
+        );
+
+        // Matches display_notes with flats
+        for root in PitchClass::all() {
+            assert_eq!(
+                major.spelled_from(root, true).join(" "),
+                major.display_notes(root)
+            );
+        }
+        assert_eq!(major.spelled_from(C, false).len(), 7);
+        assert!(ScaleFormula::empty().spelled_from(C, true).is_empty());
+    }
+
+    #[test]
+    fn test_display_notes() {
+        use crate::pitch_class::{B_FLAT, C, D, E_FLAT, F, F_SHARP, G};
+
+        let major = ScaleFormula::major();
+        assert_eq!(major.display_notes(C), "C D E F G A B");
+        assert_eq!(major.display_notes(F), "F G A Bb C D E");
+        assert_eq!(major.display_notes(G), "G A B C D E F#");
+        assert_eq!(major.display_notes(D), "D E F# G A B C#");
+        assert_eq!(major.display_notes(B_FLAT), "Bb C D Eb F G A");
+        assert_eq!(major.display_notes(E_FLAT), "Eb F G Ab Bb C D");
+
+        // Black-key roots are spelled as flats
+        assert_eq!(major.display_notes(F_SHARP), "Gb Ab Bb Cb Db Eb F");
+
+        assert_eq!(ScaleFormula::minor().display_notes(C), "C D Eb F G Ab Bb");
+        assert_eq!(ScaleFormula::lydian().display_notes(F), "F G A B C D E");
+        assert_eq!(ScaleFormula::lydian().display_notes(C), "C D E F# G A B");
+        assert_eq!(ScaleFormula::blues().display_notes(C), "C Eb F Gb G Bb");
+        assert_eq!(
+            ScaleFormula::harmonic_minor().display_notes(G),
+            "G A Bb C D Eb F#"
+        );
+
+        // Every letter is used once in a heptatonic scale
+        for root in PitchClass::all() {
+            let spelled = major.display_notes(root);
+            let mut letters: Vec<char> = spelled
+                .split(' ')
+                .map(|name| name.chars().next().unwrap())
+                .collect();
+            letters.sort();
+            assert_eq!(letters, vec!['A', 'B', 'C', 'D', 'E', 'F', 'G']);
+        }
+
+        assert_eq!(ScaleFormula::empty().display_notes(C), "");
+    }
+
+    #[test]
+    fn test_interval_quality() {
+        use crate::pitch_class::{A, A_FLAT, B, B_FLAT, C, D, E, E_FLAT, F, F_SHARP, G, G_FLAT};
+
+        let quality = |scale: ScaleFormula, root, from, to| scale.interval_quality(root, from, to);
+        let major = ScaleFormula::major();
+
+        assert_eq!(quality(major, C, C, C).as_deref(), Some("perfect unison"));
+        assert_eq!(quality(major, C, C, D).as_deref(), Some("major second"));
+        assert_eq!(quality(major, C, C, E).as_deref(), Some("major third"));
+        assert_eq!(quality(major, C, C, F).as_deref(), Some("perfect fourth"));
+        assert_eq!(quality(major, C, C, G).as_deref(), Some("perfect fifth"));
+        assert_eq!(quality(major, C, C, A).as_deref(), Some("major sixth"));
+        assert_eq!(quality(major, C, C, B).as_deref(), Some("major seventh"));
+
+        // Intervals between other degrees, measured upwards
+        assert_eq!(quality(major, C, E, G).as_deref(), Some("minor third"));
+        assert_eq!(quality(major, C, F, B).as_deref(), Some("augmented fourth"));
+        assert_eq!(quality(major, C, B, F).as_deref(), Some("diminished fifth"));
+        assert_eq!(quality(major, C, G, C).as_deref(), Some("perfect fourth"));
+        assert_eq!(
+            quality(major, F, A, B_FLAT).as_deref(),
+            Some("minor second")
+        );
+
+        // The same six semitones, spelled two ways
+        assert_eq!(
+            quality(ScaleFormula::lydian(), C, C, F_SHARP).as_deref(),
+            Some("augmented fourth")
+        );
+        assert_eq!(
+            quality(ScaleFormula::locrian(), C, C, G_FLAT).as_deref(),
+            Some("diminished fifth")
+        );
+
+        // Augmented second in harmonic minor
+        let harmonic_minor = ScaleFormula::harmonic_minor();
+        assert_eq!(
+            quality(harmonic_minor, C, A_FLAT, B).as_deref(),
+            Some("augmented second")
+        );
+        assert_eq!(
+            quality(harmonic_minor, C, B, A_FLAT).as_deref(),
+            Some("diminished seventh")
+        );
+
+        // Notes outside the scale
+        assert_eq!(quality(major, C, C, E_FLAT), None);
+        assert_eq!(quality(ScaleFormula::empty(), C, C, C), None);
+    }
+
+    #[test]
+    fn test_fold_to_octave() {
+        assert_eq!(
+            ScaleFormula::major_extended().fold_to_octave(),
+            ScaleFormula::major()
+        );
+        assert_eq!(
+            ScaleFormula::chromatic_extended().fold_to_octave(),
+            ScaleFormula::chromatic()
+        );
+
+        // Single-octave formulas are unchanged
+        assert_eq!(
+            ScaleFormula::minor().fold_to_octave(),
+            ScaleFormula::minor()
+        );
+        assert_eq!(
+            ScaleFormula::empty().fold_to_octave(),
+            ScaleFormula::empty()
+        );
+
+        // Extensions merge with their first-octave counterparts
+        let dominant_altered = ScaleFormula::from_semitones(&[0, 4, 7, 10, 13, 15, 18]);
+        assert_eq!(
+            dominant_altered.fold_to_octave(),
+            ScaleFormula::from_semitones(&[0, 1, 3, 4, 6, 7, 10])
+        );
+
+        // Only second-octave notes
+        let upper = ScaleFormula::from_semitones(&[14, 17, 21]);
+        assert_eq!(
+            upper.fold_to_octave(),
+            ScaleFormula::from_semitones(&[2, 5, 9])
+        );
+    }
+
+    #[test]
+    fn test_complement_extended() {
+        let major_extended = ScaleFormula::major_extended();
+        let complement = major_extended.complement_extended();
+
+        assert_eq!(complement.note_count(), 24 - major_extended.note_count());
+        assert_eq!(
+            complement.intersection(major_extended),
+            ScaleFormula::empty()
+        );
+        assert_eq!(
+            complement.union(major_extended),
+            ScaleFormula::chromatic_extended()
+        );
+
+        // Second-octave non-scale tones are kept
+        assert!(complement.contains_semitone(13)); // ♭9
+        assert!(complement.contains_semitone(18)); // ♯11
+        assert!(!complement.contains_semitone(21)); // 13th is in the scale
+
+        // Single-octave complement is unchanged
+        assert_eq!(ScaleFormula::major().complement().note_count(), 5);
+        assert_eq!(ScaleFormula::empty().complement_extended().note_count(), 24);
+    }
+
     #[test]
     fn test_display() {
         let major = ScaleFormula::major();
@@ -723,6 +2509,37 @@ mod tests {
         assert_eq!(set.len(), 2);
     }
 
+    #[test]
+    fn test_rooted_scales_as_keys() {
+        use crate::pitch_class::{A, C, G};
+        use std::collections::{BTreeSet, HashSet};
+
+        let scales = [
+            (C, ScaleFormula::major()),
+            (A, ScaleFormula::minor()),
+            (C, ScaleFormula::major()),
+            (G, ScaleFormula::major()),
+            (A, ScaleFormula::minor()),
+        ];
+
+        let hashed: HashSet<(PitchClass, ScaleFormula)> = scales.iter().copied().collect();
+        assert_eq!(hashed.len(), 3);
+
+        let ordered: BTreeSet<(PitchClass, ScaleFormula)> = scales.iter().copied().collect();
+        assert_eq!(
+            ordered.into_iter().collect::<Vec<_>>(),
+            vec![
+                (C, ScaleFormula::major()),
+                (G, ScaleFormula::major()),
+                (A, ScaleFormula::minor()),
+            ]
+        );
+
+        // Ordering follows the bit pattern
+        assert!(ScaleFormula::minor() < ScaleFormula::major());
+        assert!(ScaleFormula::empty() < ScaleFormula::pentatonic_major());
+    }
+
     #[test]
     fn test_contains_semitone_boundary() {
         let major = ScaleFormula::major();
@@ -761,6 +2578,41 @@ mod tests {
         assert!(!CHROMATIC.is_empty());
     }
 
+    #[test]
+    fn test_degree_frequency() {
+        use crate::pitch_class::{A, C};
+
+        let major = ScaleFormula::major();
+        let octave = Octave::new(4);
+
+        assert_eq!(
+            major.degree_frequency(C, 1, octave, 440.0),
+            Some(Note::new(60).frequency())
+        );
+        assert_eq!(
+            major.degree_frequency(C, 5, octave, 440.0),
+            Some(Note::new(67).frequency())
+        );
+        // A major from A4: the 3rd is C♯5
+        assert_eq!(
+            major.degree_frequency(A, 3, octave, 440.0),
+            Some(Note::new(73).frequency())
+        );
+        // The A4 reference is honored
+        assert_eq!(major.degree_frequency(A, 1, octave, 415.0), Some(415.0));
+
+        // Extended formulas reach into the second octave
+        assert_eq!(
+            ScaleFormula::major_extended().degree_frequency(C, 9, octave, 440.0),
+            Some(Note::new(74).frequency())
+        );
+
+        assert_eq!(major.degree_frequency(C, 0, octave, 440.0), None);
+        assert_eq!(major.degree_frequency(C, 8, octave, 440.0), None);
+        assert_eq!(major.degree_frequency(C, 1, Octave::new(-2), 440.0), None);
+        assert_eq!(major.degree_frequency(C, 7, Octave::new(20), 440.0), None);
+    }
+
     #[test]
     fn test_notes_from_root() {
         let major = ScaleFormula::major();
@@ -794,4 +2646,874 @@ mod tests {
         let empty_count = empty.notes_from_root(Note::new(0)).count();
         assert_eq!(empty_count, 0);
     }
+
+    #[test]
+    fn test_contains_pitch_class() {
+        use crate::pitch_class::{A, B_FLAT, C, D, E_FLAT, F, F_SHARP};
+
+        let major = ScaleFormula::major();
+        assert!(major.contains_pitch_class(C, C));
+        assert!(major.contains_pitch_class(F, B_FLAT));
+        assert!(!major.contains_pitch_class(C, B_FLAT));
+        assert!(major.contains_pitch_class(D, F_SHARP));
+
+        let minor = ScaleFormula::minor();
+        assert!(minor.contains_pitch_class(A, C));
+        assert!(!minor.contains_pitch_class(C, A));
+        assert!(minor.contains_pitch_class(C, E_FLAT));
+
+        // Second-octave degrees count as the same pitch class
+        let ninth_only = ScaleFormula::from_semitones(&[0, 14]);
+        assert!(ninth_only.contains_pitch_class(C, D));
+
+        assert!(!ScaleFormula::empty().contains_pitch_class(C, C));
+    }
+
+    #[test]
+    fn test_membership_mask() {
+        use crate::pitch_class::{A, C, D};
+
+        let scales = [
+            ScaleFormula::major(),
+            ScaleFormula::harmonic_minor(),
+            ScaleFormula::pentatonic_minor(),
+            ScaleFormula::blues(),
+            ScaleFormula::whole_tone(),
+            ScaleFormula::major_extended(),
+        ];
+
+        for scale in scales {
+            for root in PitchClass::all() {
+                let mask = scale.membership_mask(root);
+                assert_eq!(mask >> SEMITONES_IN_OCTAVE, 0);
+                for pitch_class in PitchClass::all() {
+                    assert_eq!(
+                        mask & (1 << pitch_class.value()) != 0,
+                        scale.contains_pitch_class(root, pitch_class)
+                    );
+                }
+            }
+        }
+
+        assert_eq!(ScaleFormula::major().membership_mask(C), 0b1010_1011_0101);
+        assert_eq!(ScaleFormula::chromatic().membership_mask(D), 0xFFF);
+        assert_eq!(ScaleFormula::empty().membership_mask(A), 0);
+    }
+
+    #[test]
+    fn test_same_pitches() {
+        use crate::pitch_class::{A, B_FLAT, C, D, E, F, F_SHARP, G_FLAT};
+
+        let major = ScaleFormula::major();
+        assert!(major.same_pitches(F_SHARP, &major, G_FLAT));
+        assert!(major.same_pitches(C, &ScaleFormula::dorian(), D));
+        assert!(major.same_pitches(F, &ScaleFormula::phrygian(), A));
+        assert!(major.same_pitches(C, &ScaleFormula::major_extended(), C));
+
+        // Same formula on different roots, or different collections on one root
+        assert!(!major.same_pitches(C, &major, F));
+        assert!(!major.same_pitches(C, &ScaleFormula::minor(), C));
+        assert!(!major.same_pitches(E, &ScaleFormula::harmonic_minor(), B_FLAT));
+
+        // The whole-tone scale only has two distinct transpositions
+        let whole_tone = ScaleFormula::whole_tone();
+        assert!(whole_tone.same_pitches(C, &whole_tone, D));
+        assert!(!whole_tone.same_pitches(C, &whole_tone, F));
+    }
+
+    #[test]
+    fn test_chromaticism() {
+        use crate::pitch_class::{A, A_FLAT, B, B_FLAT, C, D, E, E_FLAT, F, F_SHARP, G, G_SHARP};
+
+        let major = ScaleFormula::major();
+
+        // Diatonic triads and sevenths in C major
+        assert_eq!(major.chromaticism(C, &[C, E, G]), 0);
+        assert_eq!(major.chromaticism(C, &[G, B, D, F]), 0);
+        assert_eq!(major.chromaticism(C, &[]), 0);
+
+        // Secondary dominants
+        assert_eq!(major.chromaticism(C, &[D, F_SHARP, A, C]), 1); // V7/V
+        assert_eq!(major.chromaticism(C, &[E, G_SHARP, B, D]), 1); // V7/vi
+
+        // Borrowed iv and ♭VII from the parallel minor
+        assert_eq!(major.chromaticism(C, &[F, A_FLAT, C]), 1);
+        assert_eq!(major.chromaticism(C, &[B_FLAT, D, F]), 1);
+        assert_eq!(major.chromaticism(C, &[E_FLAT, G, B_FLAT]), 2);
+
+        // Same chord, different key
+        assert_eq!(major.chromaticism(D, &[D, F_SHARP, A]), 0);
+    }
+
+    #[test]
+    fn test_passing_tones() {
+        use crate::pitch_class::{A, B, B_FLAT, C, C_SHARP, D, E, F, F_SHARP, G};
+
+        let major = ScaleFormula::major();
+        assert_eq!(major.passing_tones(C, C, E), vec![D]);
+        assert_eq!(major.passing_tones(C, C, G), vec![D, E, F]);
+
+        // Adjacent scale tones and unisons have nothing between them
+        assert!(major.passing_tones(C, D, E).is_empty());
+        assert!(major.passing_tones(C, B, C).is_empty());
+        assert!(major.passing_tones(C, D, D).is_empty());
+
+        // Wraps around the octave
+        assert_eq!(major.passing_tones(C, A, D), vec![B, C]);
+
+        // Follows the key, and chromatic endpoints are allowed
+        assert_eq!(major.passing_tones(G, E, G), vec![F_SHARP]);
+        assert_eq!(major.passing_tones(C, C_SHARP, F_SHARP), vec![D, E, F]);
+        assert_eq!(
+            ScaleFormula::mixolydian().passing_tones(C, G, C),
+            vec![A, B_FLAT]
+        );
+    }
+
+    #[test]
+    fn test_diatonic_transpose() {
+        use crate::pitch_class::{A, B, C, C_SHARP, D, E, F, F_SHARP, G};
+
+        let major = ScaleFormula::major();
+        let melody = [C, D, E, F, G, A, B];
+
+        // Up a third
+        assert_eq!(
+            major.diatonic_transpose(C, &melody, 2),
+            vec![E, F, G, A, B, C, D]
+        );
+
+        // Down a third undoes it, and a full octave of steps is the identity
+        let up = major.diatonic_transpose(C, &melody, 2);
+        assert_eq!(major.diatonic_transpose(C, &up, -2), melody.to_vec());
+        assert_eq!(major.diatonic_transpose(C, &melody, 7), melody.to_vec());
+        assert_eq!(major.diatonic_transpose(C, &melody, 0), melody.to_vec());
+
+        // Stays in the key: G major keeps the F♯
+        assert_eq!(major.diatonic_transpose(G, &[D, E], 2), vec![F_SHARP, G]);
+
+        // Chromatic notes snap to the scale on the first step
+        assert_eq!(major.diatonic_transpose(C, &[C_SHARP], 1), vec![D]);
+        assert_eq!(major.diatonic_transpose(C, &[C_SHARP], -1), vec![C]);
+
+        // Nothing to step through
+        assert_eq!(
+            ScaleFormula::empty().diatonic_transpose(C, &[E], 3),
+            vec![E]
+        );
+    }
+
+    #[test]
+    fn test_step_up_down() {
+        use crate::pitch_class::{A, B, C, C_SHARP, D, E, E_FLAT, F, F_SHARP, G};
+
+        let major = ScaleFormula::major();
+
+        // In-scale stepping
+        assert_eq!(major.step_up(C, E), Some(F));
+        assert_eq!(major.step_up(C, F), Some(G));
+        assert_eq!(major.step_down(C, F), Some(E));
+        assert_eq!(major.step_down(C, D), Some(C));
+
+        // Wraparound at the octave boundary
+        assert_eq!(major.step_up(C, B), Some(C));
+        assert_eq!(major.step_down(C, C), Some(B));
+        assert_eq!(major.step_up(G, F_SHARP), Some(G));
+
+        // Out-of-scale notes resolve to the nearest scale tone in that direction
+        assert_eq!(major.step_up(C, C_SHARP), Some(D));
+        assert_eq!(major.step_down(C, C_SHARP), Some(C));
+        assert_eq!(major.step_up(C, E_FLAT), Some(E));
+        assert_eq!(major.step_down(C, E_FLAT), Some(D));
+
+        // Walking up the whole scale returns to the start
+        let mut note = C;
+        let mut walked = Vec::new();
+        for _ in 0..7 {
+            note = major.step_up(C, note).unwrap();
+            walked.push(note);
+        }
+        assert_eq!(walked, vec![D, E, F, G, A, B, C]);
+
+        // A single-note scale steps back to itself
+        let root_only = ScaleFormula::from_semitones(&[0]);
+        assert_eq!(root_only.step_up(C, C), Some(C));
+        assert_eq!(root_only.step_down(C, G), Some(C));
+
+        assert_eq!(ScaleFormula::empty().step_up(C, C), None);
+        assert_eq!(ScaleFormula::empty().step_down(C, C), None);
+    }
+
+    #[test]
+    fn test_interval_vector() {
+        assert_eq!(ScaleFormula::major().interval_vector(), [2, 5, 4, 3, 6, 1]);
+        assert_eq!(
+            ScaleFormula::major_extended().interval_vector(),
+            [2, 5, 4, 3, 6, 1]
+        );
+
+        // Every pair of the twelve notes: each class appears twelve times,
+        // except the tritone, which is its own inversion
+        assert_eq!(
+            ScaleFormula::chromatic().interval_vector(),
+            [12, 12, 12, 12, 12, 6]
+        );
+
+        // Modes share the vector of their parent scale
+        for mode in [
+            ScaleFormula::dorian(),
+            ScaleFormula::locrian(),
+            ScaleFormula::minor(),
+        ] {
+            assert_eq!(
+                mode.interval_vector(),
+                ScaleFormula::major().interval_vector()
+            );
+        }
+
+        // A scale with n notes has n(n-1)/2 pairs
+        let blues = ScaleFormula::blues();
+        let pairs: u8 = blues.interval_vector().iter().sum();
+        let count = blues.note_count();
+        assert_eq!(pairs, count * (count - 1) / 2);
+
+        assert_eq!(
+            ScaleFormula::pentatonic_major().interval_vector(),
+            [0, 3, 2, 1, 4, 0]
+        );
+        assert_eq!(ScaleFormula::empty().interval_vector(), [0; 6]);
+    }
+
+    #[test]
+    fn test_largest_gap() {
+        assert_eq!(ScaleFormula::major().largest_gap(), 2);
+        assert_eq!(ScaleFormula::minor().largest_gap(), 2);
+        assert_eq!(ScaleFormula::pentatonic_major().largest_gap(), 3);
+        assert_eq!(ScaleFormula::pentatonic_minor().largest_gap(), 3);
+        assert_eq!(ScaleFormula::harmonic_minor().largest_gap(), 3);
+        assert_eq!(ScaleFormula::chromatic().largest_gap(), 1);
+
+        // Extended formulas behave like their single-octave counterparts
+        assert_eq!(ScaleFormula::major_extended().largest_gap(), 2);
+
+        // The wrap from the highest note back to the octave counts
+        assert_eq!(ScaleFormula::from_semitones(&[0, 4, 7]).largest_gap(), 5);
+        assert_eq!(ScaleFormula::from_semitones(&[2, 3]).largest_gap(), 11);
+
+        assert_eq!(ScaleFormula::from_semitones(&[0]).largest_gap(), 12);
+        assert_eq!(ScaleFormula::empty().largest_gap(), 0);
+    }
+
+    #[test]
+    fn test_has_leading_tone() {
+        assert!(ScaleFormula::major().has_leading_tone());
+        assert!(ScaleFormula::harmonic_minor().has_leading_tone());
+        assert!(ScaleFormula::lydian().has_leading_tone());
+        assert!(ScaleFormula::chromatic().has_leading_tone());
+
+        assert!(!ScaleFormula::minor().has_leading_tone());
+        assert!(!ScaleFormula::mixolydian().has_leading_tone());
+        assert!(!ScaleFormula::pentatonic_major().has_leading_tone());
+        assert!(!ScaleFormula::empty().has_leading_tone());
+    }
+
+    #[test]
+    fn test_iter_semitones() {
+        let formulas = [
+            ScaleFormula::major(),
+            ScaleFormula::major_extended(),
+            ScaleFormula::minor(),
+            ScaleFormula::pentatonic_minor(),
+            ScaleFormula::blues(),
+            ScaleFormula::chromatic_extended(),
+            ScaleFormula::empty(),
+        ];
+
+        for formula in formulas {
+            let lazy: Vec<u8> = formula.iter_semitones().collect();
+            assert_eq!(lazy, formula.semitones());
+            assert_eq!(
+                formula.iter_semitones().count(),
+                formula.note_count() as usize
+            );
+            assert_eq!(
+                formula.notes_from_root(Note::new(60)).count(),
+                formula.note_count() as usize
+            );
+        }
+
+        // Lazy iteration stops early without visiting the rest
+        let first_two: Vec<u8> = ScaleFormula::minor().iter_semitones().take(2).collect();
+        assert_eq!(first_two, vec![0, 2]);
+    }
+
+    #[test]
+    fn test_with_altered_degree() {
+        let major = ScaleFormula::major();
+
+        // Lydian: raised 4th
+        let lydian = major.with_altered_degree(4, DegreeAlteration::Sharp);
+        assert_eq!(lydian.semitones(), vec![0, 2, 4, 6, 7, 9, 11]);
+
+        // Lowered 3rd gives a minor third
+        let lowered_third = major.with_altered_degree(3, DegreeAlteration::Flat);
+        assert!(lowered_third.contains_semitone(3));
+        assert!(!lowered_third.contains_semitone(4));
+        assert_eq!(lowered_third.note_count(), 7);
+
+        // Mixolydian: lowered 7th
+        let mixolydian = major.with_altered_degree(7, DegreeAlteration::Flat);
+        assert_eq!(mixolydian.semitones(), vec![0, 2, 4, 5, 7, 9, 10]);
+
+        // Harmonic minor: raised 7th of natural minor
+        let harmonic_minor = ScaleFormula::minor().with_altered_degree(7, DegreeAlteration::Sharp);
+        assert_eq!(harmonic_minor.semitones(), vec![0, 2, 3, 5, 7, 8, 11]);
+
+        // Natural alteration and out-of-range degrees leave the formula unchanged
+        assert_eq!(major.with_altered_degree(2, DegreeAlteration::None), major);
+        assert_eq!(major.with_altered_degree(0, DegreeAlteration::Sharp), major);
+        assert_eq!(major.with_altered_degree(8, DegreeAlteration::Sharp), major);
+
+        // Altering onto an existing note merges them
+        let merged = major.with_altered_degree(4, DegreeAlteration::Flat);
+        assert_eq!(merged.note_count(), 6);
+
+        // Altered notes wrap within their own octave
+        let flat_root = major.with_altered_degree(1, DegreeAlteration::Flat);
+        assert!(!flat_root.contains_semitone(0));
+        assert!(flat_root.contains_semitone(11));
+
+        // Extended degrees stay in the second octave
+        let extended = ScaleFormula::major_extended();
+        let sharp_eleven = extended.with_altered_degree(11, DegreeAlteration::Sharp);
+        assert!(sharp_eleven.contains_semitone(18)); // ♯11
+        assert!(!sharp_eleven.contains_semitone(17));
+        assert!(sharp_eleven.contains_semitone(5)); // First-octave 4th untouched
+    }
+
+    #[test]
+    fn test_jaccard_similarity() {
+        let major = ScaleFormula::major();
+        let minor = ScaleFormula::minor();
+
+        assert_eq!(major.jaccard_similarity(&major), 1.0);
+        assert_eq!(major.jaccard_similarity(&minor), 0.4);
+        assert_eq!(minor.jaccard_similarity(&major), 0.4); // Symmetric
+
+        // Disjoint formulas
+        let whole_tone_a = ScaleFormula::from_semitones(&[0, 2, 4, 6, 8, 10]);
+        let whole_tone_b = ScaleFormula::from_semitones(&[1, 3, 5, 7, 9, 11]);
+        assert_eq!(whole_tone_a.jaccard_similarity(&whole_tone_b), 0.0);
+
+        // Subsets
+        let pentatonic = ScaleFormula::pentatonic_major();
+        assert_eq!(pentatonic.jaccard_similarity(&major), 5.0 / 7.0);
+
+        // Only the first octave is compared
+        let extended = ScaleFormula::major_extended();
+        assert_eq!(extended.jaccard_similarity(&major), 1.0);
+
+        // Empty formulas
+        let empty = ScaleFormula::empty();
+        assert_eq!(empty.jaccard_similarity(&empty), 1.0);
+        assert_eq!(empty.jaccard_similarity(&major), 0.0);
+    }
+
+    #[test]
+    fn test_pitch_classes() {
+        use crate::pitch_class::{A, B, B_FLAT, C, D, E, F, G};
+
+        assert_eq!(
+            ScaleFormula::major().pitch_classes(C),
+            vec![C, D, E, F, G, A, B]
+        );
+        assert_eq!(
+            ScaleFormula::major().pitch_classes(F),
+            vec![F, G, A, B_FLAT, C, D, E]
+        );
+        assert_eq!(
+            ScaleFormula::major_extended().pitch_classes(C),
+            ScaleFormula::major().pitch_classes(C)
+        );
+        assert!(ScaleFormula::empty().pitch_classes(C).is_empty());
+
+        // Whole-tone pitch classes are two semitones apart
+        let whole_tone = ScaleFormula::whole_tone().pitch_classes(C);
+        assert_eq!(whole_tone.len(), 6);
+        for pair in whole_tone.windows(2) {
+            assert_eq!(pair[1].value() - pair[0].value(), 2);
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_random_note() {
+        use crate::pitch_class::{C, E_FLAT};
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let major = ScaleFormula::major();
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut seen = Vec::new();
+        for _ in 0..200 {
+            let note = major.random_note(E_FLAT, &mut rng).unwrap();
+            assert!(major.contains_pitch_class(E_FLAT, note));
+            if !seen.contains(&note) {
+                seen.push(note);
+            }
+        }
+        // Every scale note shows up eventually
+        assert_eq!(seen.len(), 7);
+
+        assert_eq!(ScaleFormula::empty().random_note(C, &mut rng), None);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_random_note_seeded() {
+        use crate::pitch_class::C;
+
+        let blues = ScaleFormula::blues();
+        for seed in 0..50 {
+            let note = blues.random_note_seeded(C, seed);
+            assert_eq!(note, blues.random_note_seeded(C, seed));
+            assert!(blues.contains_pitch_class(C, note.unwrap()));
+        }
+
+        assert_eq!(ScaleFormula::empty().random_note_seeded(C, 1), None);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_generate_progression() {
+        use crate::pitch_class::{A, B, C, D, E, F, G};
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let major = ScaleFormula::major();
+        let tonic = (C, ChordFormula::major_triad());
+        let mut rng = StdRng::seed_from_u64(7);
+
+        for length in 2..=16 {
+            let progression = major.generate_progression(C, length, &mut rng);
+            assert_eq!(progression.len(), length);
+            assert_eq!(progression[0], tonic);
+            assert_eq!(progression[length - 1], tonic);
+
+            // Every chord is diatonic
+            for &(chord_root, chord) in &progression {
+                for pitch_class in chord.pitch_classes(chord_root) {
+                    assert!(major.contains_pitch_class(C, pitch_class));
+                }
+            }
+
+            // Dominants never move to a subdominant
+            for pair in progression.windows(2) {
+                if [G, B].contains(&pair[0].0) {
+                    assert!(![F, D].contains(&pair[1].0));
+                }
+            }
+        }
+
+        // Every diatonic root shows up eventually
+        let long = major.generate_progression(C, 200, &mut rng);
+        for root in [C, D, E, F, G, A, B] {
+            assert!(long.iter().any(|&(chord_root, _)| chord_root == root));
+        }
+
+        assert_eq!(major.generate_progression(C, 1, &mut rng), vec![tonic]);
+        assert!(major.generate_progression(C, 0, &mut rng).is_empty());
+        assert!(ScaleFormula::pentatonic_major()
+            .generate_progression(C, 4, &mut rng)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_modes() {
+        assert_eq!(
+            ScaleFormula::dorian().semitones(),
+            vec![0, 2, 3, 5, 7, 9, 10]
+        );
+        assert_eq!(
+            ScaleFormula::phrygian().semitones(),
+            vec![0, 1, 3, 5, 7, 8, 10]
+        );
+        assert_eq!(
+            ScaleFormula::lydian().semitones(),
+            vec![0, 2, 4, 6, 7, 9, 11]
+        );
+        assert_eq!(
+            ScaleFormula::mixolydian().semitones(),
+            vec![0, 2, 4, 5, 7, 9, 10]
+        );
+        assert_eq!(
+            ScaleFormula::locrian().semitones(),
+            vec![0, 1, 3, 5, 6, 8, 10]
+        );
+
+        // Modes of C major share its pitch classes
+        use crate::pitch_class::{A, B, C, D, E, F, G};
+        let c_major = ScaleFormula::major().pitch_classes(C);
+        let modes = [
+            (D, ScaleFormula::dorian()),
+            (E, ScaleFormula::phrygian()),
+            (F, ScaleFormula::lydian()),
+            (G, ScaleFormula::mixolydian()),
+            (A, ScaleFormula::minor()),
+            (B, ScaleFormula::locrian()),
+        ];
+        for (root, mode) in modes {
+            let mut pitch_classes = mode.pitch_classes(root);
+            pitch_classes.sort();
+            assert_eq!(pitch_classes, c_major);
+        }
+    }
+
+    #[test]
+    fn test_triad_quality() {
+        use ChordQuality::{Augmented, Diminished, Major, Minor};
+
+        let major = ScaleFormula::major();
+        let qualities: Vec<Option<ChordQuality>> =
+            (1..=7).map(|degree| major.triad_quality(degree)).collect();
+        assert_eq!(
+            qualities,
+            vec![
+                Some(Major),
+                Some(Minor),
+                Some(Minor),
+                Some(Major),
+                Some(Major),
+                Some(Minor),
+                Some(Diminished)
+            ]
+        );
+
+        let minor = ScaleFormula::minor();
+        assert_eq!(minor.triad_quality(1), Some(Minor));
+        assert_eq!(minor.triad_quality(2), Some(Diminished));
+        assert_eq!(minor.triad_quality(3), Some(Major));
+
+        let harmonic_minor = ScaleFormula::harmonic_minor();
+        assert_eq!(harmonic_minor.triad_quality(3), Some(Augmented));
+        assert_eq!(harmonic_minor.triad_quality(5), Some(Major));
+        assert_eq!(harmonic_minor.triad_quality(7), Some(Diminished));
+
+        // Extended formulas use their first octave
+        assert_eq!(
+            ScaleFormula::major_extended().triad_quality(7),
+            Some(Diminished)
+        );
+
+        // Out of range degrees and non-tertian stacks
+        assert_eq!(major.triad_quality(0), None);
+        assert_eq!(major.triad_quality(8), None);
+        assert_eq!(ScaleFormula::empty().triad_quality(1), None);
+        assert_eq!(ScaleFormula::chromatic().triad_quality(1), None);
+    }
+
+    #[test]
+    fn test_mode() {
+        let major = ScaleFormula::major();
+        let names: Vec<Option<&str>> = (1..=7)
+            .map(|degree| major.mode(degree).unwrap().name())
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                Some("Major"),
+                Some("Dorian"),
+                Some("Phrygian"),
+                Some("Lydian"),
+                Some("Mixolydian"),
+                Some("Minor"),
+                Some("Locrian"),
+            ]
+        );
+
+        // Relative major and minor are modes of each other
+        assert_eq!(ScaleFormula::minor().mode(3), Some(major));
+        assert_eq!(
+            ScaleFormula::pentatonic_major().mode(5),
+            Some(ScaleFormula::pentatonic_minor())
+        );
+
+        // Extended formulas rotate their first octave
+        assert_eq!(
+            ScaleFormula::major_extended().mode(2),
+            Some(ScaleFormula::dorian())
+        );
+
+        assert_eq!(major.mode(0), None);
+        assert_eq!(major.mode(8), None);
+        assert_eq!(ScaleFormula::empty().mode(1), None);
+    }
+
+    #[test]
+    fn test_from_name() {
+        assert_eq!(
+            ScaleFormula::from_name("Major"),
+            Some(ScaleFormula::major())
+        );
+        assert_eq!(
+            ScaleFormula::from_name("MINOR"),
+            Some(ScaleFormula::minor())
+        );
+        assert_eq!(
+            ScaleFormula::from_name("pentatonic minor"),
+            Some(ScaleFormula::pentatonic_minor())
+        );
+
+        assert_eq!(ScaleFormula::from_name("Bebop"), None);
+        assert_eq!(ScaleFormula::from_name(""), None);
+        assert_eq!(ScaleFormula::from_name("Major "), None);
+    }
+
+    #[test]
+    fn test_all_named() {
+        let all: Vec<ScaleFormula> = ScaleFormula::all_named().collect();
+        assert_eq!(all.len(), 17);
+        assert_eq!(all[0], ScaleFormula::major());
+
+        // Every formula round-trips through name() and from_name()
+        for formula in &all {
+            let name = formula.name().unwrap();
+            assert_eq!(ScaleFormula::from_name(name), Some(*formula));
+        }
+
+        // No duplicates
+        for (index, formula) in all.iter().enumerate() {
+            assert!(!all[index + 1..].contains(formula));
+        }
+    }
+
+    #[test]
+    fn test_tendency() {
+        let major = ScaleFormula::major();
+        assert_eq!(major.tendency(7), Some(Tendency::new(1, Contour::Up)));
+        assert_eq!(major.tendency(4), Some(Tendency::new(3, Contour::Down)));
+        assert_eq!(major.tendency(6), Some(Tendency::new(5, Contour::Down)));
+        assert_eq!(major.tendency(2), Some(Tendency::new(1, Contour::Down)));
+        for stable in [1, 3, 5] {
+            assert_eq!(major.tendency(stable), None);
+        }
+
+        // Natural minor: the 2nd leans up to ♭3, the ♭6 falls to 5
+        let minor = ScaleFormula::minor();
+        assert_eq!(minor.tendency(2), Some(Tendency::new(3, Contour::Up)));
+        assert_eq!(minor.tendency(6), Some(Tendency::new(5, Contour::Down)));
+        assert_eq!(minor.tendency(7), Some(Tendency::new(1, Contour::Up)));
+
+        // Harmonic minor: the raised 7th is a leading tone again
+        let harmonic_minor = ScaleFormula::harmonic_minor();
+        assert_eq!(
+            harmonic_minor.tendency(7),
+            Some(Tendency::new(1, Contour::Up))
+        );
+        assert_eq!(
+            harmonic_minor.tendency(4),
+            Some(Tendency::new(3, Contour::Down))
+        );
+
+        // Every active degree of a diatonic mode has a tendency
+        for formula in ScaleFormula::all_named().filter(ScaleFormula::is_diatonic) {
+            for degree in [2, 4, 6, 7] {
+                assert!(formula.tendency(degree).is_some());
+            }
+        }
+
+        assert_eq!(major.tendency(0), None);
+        assert_eq!(major.tendency(8), None);
+        assert_eq!(
+            ScaleFormula::major_extended().tendency(7),
+            Some(Tendency::new(1, Contour::Up))
+        );
+        assert_eq!(ScaleFormula::pentatonic_major().tendency(2), None);
+        assert_eq!(
+            ScaleFormula::from_semitones(&[2, 3, 4, 5, 7, 9, 11]).tendency(2),
+            None
+        );
+        assert_eq!(ScaleFormula::empty().tendency(2), None);
+    }
+
+    #[test]
+    fn test_is_rotation_of() {
+        let major = ScaleFormula::major();
+        for degree in 1..=7 {
+            assert!(major.mode(degree).unwrap().is_rotation_of(&major));
+        }
+        assert!(major.is_rotation_of(&ScaleFormula::locrian()));
+        assert!(ScaleFormula::octatonic_wh().is_rotation_of(&ScaleFormula::octatonic_hw()));
+
+        // Extended formulas are folded to one octave before comparing
+        assert!(ScaleFormula::major_extended().is_rotation_of(&major));
+        assert!(ScaleFormula::major_extended().is_rotation_of(&ScaleFormula::major_extended()));
+        assert!(ScaleFormula::chromatic_extended().is_rotation_of(&ScaleFormula::chromatic()));
+        assert!(!ScaleFormula::from_semitones(&[0, 4, 7, 13]).is_rotation_of(&major));
+
+        assert!(!ScaleFormula::harmonic_minor().is_rotation_of(&major));
+        assert!(!major.is_rotation_of(&ScaleFormula::pentatonic_major()));
+        assert!(!ScaleFormula::empty().is_rotation_of(&ScaleFormula::empty()));
+    }
+
+    #[test]
+    fn test_is_diatonic() {
+        for formula in [
+            ScaleFormula::major(),
+            ScaleFormula::dorian(),
+            ScaleFormula::phrygian(),
+            ScaleFormula::lydian(),
+            ScaleFormula::mixolydian(),
+            ScaleFormula::minor(),
+            ScaleFormula::locrian(),
+            ScaleFormula::major_extended(),
+        ] {
+            assert!(formula.is_diatonic());
+        }
+
+        for formula in [
+            ScaleFormula::whole_tone(),
+            ScaleFormula::blues(),
+            ScaleFormula::harmonic_minor(),
+            ScaleFormula::altered(),
+            ScaleFormula::pentatonic_major(),
+            ScaleFormula::chromatic(),
+        ] {
+            assert!(!formula.is_diatonic());
+        }
+    }
+
+    #[test]
+    fn test_solfege() {
+        use crate::pitch_class::{A, B, B_FLAT, C, D, D_SHARP, E, F, F_SHARP, G, G_SHARP};
+
+        let major = ScaleFormula::major();
+        let syllables: Vec<&str> = major
+            .pitch_classes(C)
+            .into_iter()
+            .filter_map(|note| major.solfege(C, note))
+            .collect();
+        assert_eq!(syllables, vec!["do", "re", "mi", "fa", "sol", "la", "ti"]);
+
+        // Movable do follows the tonic
+        assert_eq!(major.solfege(D, D), Some("do"));
+        assert_eq!(major.solfege(F, A), Some("mi"));
+        assert_eq!(major.solfege(G, C), Some("fa"));
+
+        // Chromatic alterations
+        assert_eq!(major.solfege(C, F_SHARP), Some("fi"));
+        assert_eq!(major.solfege(C, B_FLAT), Some("te"));
+        assert_eq!(major.solfege(E, C), Some("le"));
+
+        // Minor uses do-based minor syllables
+        let minor = ScaleFormula::minor();
+        let syllables: Vec<&str> = minor
+            .pitch_classes(A)
+            .into_iter()
+            .filter_map(|note| minor.solfege(A, note))
+            .collect();
+        assert_eq!(syllables, vec!["do", "re", "me", "fa", "sol", "le", "te"]);
+
+        // Scale tones take the syllable of their spelling in the scale
+        assert_eq!(ScaleFormula::locrian().solfege(C, F_SHARP), Some("se"));
+        assert_eq!(ScaleFormula::lydian().solfege(C, F_SHARP), Some("fi"));
+        assert_eq!(ScaleFormula::blues().solfege(C, F_SHARP), Some("se"));
+        assert_eq!(ScaleFormula::phrygian().solfege(E, F), Some("ra"));
+        assert_eq!(
+            ScaleFormula::harmonic_minor().solfege(A, G_SHARP),
+            Some("ti")
+        );
+        assert_eq!(ScaleFormula::altered().solfege(C, D_SHARP), Some("ri"));
+        let lydian_augmented = ScaleFormula::from_semitones(&[0, 2, 4, 6, 8, 9, 11]);
+        assert_eq!(lydian_augmented.solfege(C, G_SHARP), Some("si"));
+
+        let locrian = ScaleFormula::locrian();
+        let syllables: Vec<&str> = locrian
+            .pitch_classes(B)
+            .into_iter()
+            .filter_map(|note| locrian.solfege(B, note))
+            .collect();
+        assert_eq!(syllables, vec!["do", "ra", "me", "fa", "se", "le", "te"]);
+
+        assert_eq!(ScaleFormula::empty().solfege(C, B), None);
+    }
+
+    #[test]
+    fn test_parent_major() {
+        use crate::pitch_class::{A, B, C, D, E, F, G};
+
+        // The white-key modes all belong to C major
+        let modes = [
+            (C, ScaleFormula::major()),
+            (D, ScaleFormula::dorian()),
+            (E, ScaleFormula::phrygian()),
+            (F, ScaleFormula::lydian()),
+            (G, ScaleFormula::mixolydian()),
+            (A, ScaleFormula::minor()),
+            (B, ScaleFormula::locrian()),
+        ];
+        for (root, formula) in modes {
+            assert_eq!(formula.parent_major(root), Some(C));
+        }
+
+        assert_eq!(ScaleFormula::mixolydian().parent_major(C), Some(F));
+        assert_eq!(ScaleFormula::lydian().parent_major(C), Some(G));
+        assert_eq!(ScaleFormula::major_extended().parent_major(D), Some(D));
+        assert_eq!(ScaleFormula::whole_tone().parent_major(C), None);
+    }
+
+    #[test]
+    fn test_name() {
+        assert_eq!(
+            ScaleFormula::harmonic_minor().name(),
+            Some("Harmonic Minor")
+        );
+        assert_eq!(ScaleFormula::blues().name(), Some("Blues"));
+        assert_eq!(ScaleFormula::chromatic().name(), Some("Chromatic"));
+        assert_eq!(
+            ScaleFormula::pentatonic_minor().name(),
+            Some("Pentatonic Minor")
+        );
+
+        assert_eq!(ScaleFormula::empty().name(), None);
+        assert_eq!(ScaleFormula::major_extended().name(), None);
+    }
+
+    #[test]
+    fn test_degree_labels() {
+        assert_eq!(
+            ScaleFormula::major().degree_labels(),
+            vec!["1", "2", "3", "4", "5", "6", "7"]
+        );
+        assert_eq!(
+            ScaleFormula::minor().degree_labels(),
+            vec!["1", "2", "♭3", "4", "5", "♭6", "♭7"]
+        );
+        assert_eq!(
+            ScaleFormula::blues().degree_labels(),
+            vec!["1", "♭3", "4", "♭5", "5", "♭7"]
+        );
+        assert_eq!(
+            ScaleFormula::locrian().degree_labels(),
+            vec!["1", "♭2", "♭3", "4", "♭5", "♭6", "♭7"]
+        );
+        assert_eq!(
+            ScaleFormula::harmonic_minor().degree_labels(),
+            vec!["1", "2", "♭3", "4", "5", "♭6", "7"]
+        );
+
+        // Sharp spelling when the flat would double a natural degree
+        assert_eq!(
+            ScaleFormula::lydian().degree_labels(),
+            vec!["1", "2", "3", "♯4", "5", "6", "7"]
+        );
+
+        // Compound degrees in the second octave
+        let extended = ScaleFormula::from_semitones(&[0, 4, 7, 10, 13, 18, 21]);
+        assert_eq!(
+            extended.degree_labels(),
+            vec!["1", "3", "5", "♭7", "♭9", "♯11", "13"]
+        );
+
+        assert!(ScaleFormula::empty().degree_labels().is_empty());
+        assert_eq!(ScaleFormula::chromatic().degree_labels().len(), 12);
+    }
 }
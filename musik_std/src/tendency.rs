@@ -0,0 +1,44 @@
+//! Resolution tendencies of active scale degrees.
+//!
+//! This module provides the `Tendency` struct, which describes where an
+//! unstable scale degree wants to resolve in classical voice leading.
+
+use crate::melody::Contour;
+
+/// The scale degree an active degree resolves to, and the direction it moves in.
+///
+/// # Examples
+/// ```
+/// use musik_std::{Contour, ScaleFormula, Tendency};
+///
+/// // The leading tone resolves up to the tonic
+/// let leading_tone = ScaleFormula::major().tendency(7);
+/// assert_eq!(leading_tone, Some(Tendency::new(1, Contour::Up)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Tendency {
+    /// The 1-based scale degree resolved to.
+    pub target: u8,
+    /// Whether the resolution moves up or down.
+    pub direction: Contour,
+}
+
+impl Tendency {
+    /// Creates a tendency towards `target` in the given direction.
+    pub const fn new(target: u8, direction: Contour) -> Self {
+        Self { target, direction }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tendency_new() {
+        let tendency = Tendency::new(3, Contour::Down);
+        assert_eq!(tendency.target, 3);
+        assert_eq!(tendency.direction, Contour::Down);
+        assert_ne!(tendency, Tendency::new(3, Contour::Up));
+    }
+}
@@ -0,0 +1,172 @@
+//! Transposition with an explicit overflow policy.
+//!
+//! The arithmetic operators differ in how they handle overflow: `Semitone`
+//! wraps while `Note` saturates. This module provides the `Transpose` trait,
+//! which lets callers choose the behavior with an `OverflowPolicy` instead.
+
+use crate::note::{Note, MIDI_NOTE_COUNT};
+use crate::semitone::Semitone;
+
+/// How a transposition that leaves the valid range is handled.
+///
+/// # Examples
+/// ```
+/// use musik_std::{Note, OverflowPolicy, Transpose};
+///
+/// let g9 = Note::new(127);
+/// assert_eq!(g9.transpose_with(1, OverflowPolicy::Wrap), Some(Note::new(0)));
+/// assert_eq!(g9.transpose_with(1, OverflowPolicy::Saturate), Some(g9));
+/// assert_eq!(g9.transpose_with(1, OverflowPolicy::Checked), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OverflowPolicy {
+    /// Continue from the other end of the range.
+    Wrap,
+    /// Stop at the nearest end of the range.
+    Saturate,
+    /// Fail with `None`.
+    Checked,
+}
+
+/// Types that can be transposed by a number of semitones.
+pub trait Transpose: Sized {
+    /// Transposes by `semitones`, handling overflow according to `policy`.
+    ///
+    /// Returns `None` only for [`OverflowPolicy::Checked`] when the result
+    /// leaves the valid range.
+    fn transpose_with(&self, semitones: i8, policy: OverflowPolicy) -> Option<Self>;
+}
+
+impl Transpose for Note {
+    /// Transposes within the MIDI range (0-127).
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{Note, OverflowPolicy, Transpose};
+    ///
+    /// let c4 = Note::new(60);
+    /// assert_eq!(c4.transpose_with(-12, OverflowPolicy::Checked), Some(Note::new(48)));
+    /// assert_eq!(Note::new(0).transpose_with(-1, OverflowPolicy::Wrap), Some(Note::new(127)));
+    /// ```
+    fn transpose_with(&self, semitones: i8, policy: OverflowPolicy) -> Option<Self> {
+        let value = u8::from(*self);
+        shift(value, semitones, MIDI_NOTE_COUNT as i16, policy).map(Note::new)
+    }
+}
+
+impl Transpose for Semitone {
+    /// Transposes within the `u8` range (0-255), matching the wrapping operators.
+    ///
+    /// # Examples
+    /// ```
+    /// use musik_std::{OverflowPolicy, Semitone, Transpose};
+    ///
+    /// let zero = Semitone::new(0);
+    /// assert_eq!(zero.transpose_with(-1, OverflowPolicy::Wrap), Some(Semitone::new(255)));
+    /// assert_eq!(zero.transpose_with(-1, OverflowPolicy::Saturate), Some(zero));
+    /// ```
+    fn transpose_with(&self, semitones: i8, policy: OverflowPolicy) -> Option<Self> {
+        let value = u8::from(*self);
+        shift(value, semitones, u8::MAX as i16 + 1, policy).map(Semitone::new)
+    }
+}
+
+/// Shifts `value` by `semitones` within `0..size` according to `policy`.
+fn shift(value: u8, semitones: i8, size: i16, policy: OverflowPolicy) -> Option<u8> {
+    let shifted = value as i16 + semitones as i16;
+    match policy {
+        OverflowPolicy::Wrap => Some(shifted.rem_euclid(size) as u8),
+        OverflowPolicy::Saturate => Some(shifted.clamp(0, size - 1) as u8),
+        OverflowPolicy::Checked => (0..size).contains(&shifted).then_some(shifted as u8),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POLICIES: [OverflowPolicy; 3] = [
+        OverflowPolicy::Wrap,
+        OverflowPolicy::Saturate,
+        OverflowPolicy::Checked,
+    ];
+
+    #[test]
+    fn test_policies_agree_in_range() {
+        for policy in POLICIES {
+            assert_eq!(Note::new(60).transpose_with(7, policy), Some(Note::new(67)));
+            assert_eq!(
+                Note::new(60).transpose_with(-60, policy),
+                Some(Note::new(0))
+            );
+            assert_eq!(
+                Semitone::new(200).transpose_with(55, policy),
+                Some(Semitone::new(255))
+            );
+        }
+    }
+
+    #[test]
+    fn test_note_boundaries() {
+        let lowest = Note::new(0);
+        let highest = Note::new(127);
+
+        assert_eq!(
+            highest.transpose_with(1, OverflowPolicy::Wrap),
+            Some(lowest)
+        );
+        assert_eq!(
+            lowest.transpose_with(-1, OverflowPolicy::Wrap),
+            Some(highest)
+        );
+        assert_eq!(
+            highest.transpose_with(i8::MAX, OverflowPolicy::Saturate),
+            Some(highest)
+        );
+        assert_eq!(
+            lowest.transpose_with(i8::MIN, OverflowPolicy::Saturate),
+            Some(lowest)
+        );
+        assert_eq!(highest.transpose_with(1, OverflowPolicy::Checked), None);
+        assert_eq!(lowest.transpose_with(-1, OverflowPolicy::Checked), None);
+
+        // Wrapping agrees with the inherent wrapping transposition
+        for semitones in i8::MIN..=i8::MAX {
+            assert_eq!(
+                Note::new(64).transpose_with(semitones, OverflowPolicy::Wrap),
+                Some(Note::new(64).transpose_wrapping(semitones))
+            );
+        }
+    }
+
+    #[test]
+    fn test_semitone_boundaries() {
+        let lowest = Semitone::new(0);
+        let highest = Semitone::new(255);
+
+        assert_eq!(
+            highest.transpose_with(1, OverflowPolicy::Wrap),
+            Some(lowest)
+        );
+        assert_eq!(
+            lowest.transpose_with(-1, OverflowPolicy::Wrap),
+            Some(highest)
+        );
+        assert_eq!(
+            highest.transpose_with(1, OverflowPolicy::Saturate),
+            Some(highest)
+        );
+        assert_eq!(
+            lowest.transpose_with(-1, OverflowPolicy::Saturate),
+            Some(lowest)
+        );
+        assert_eq!(highest.transpose_with(1, OverflowPolicy::Checked), None);
+        assert_eq!(lowest.transpose_with(-1, OverflowPolicy::Checked), None);
+
+        // Wrapping agrees with the wrapping operators
+        assert_eq!(
+            highest.transpose_with(3, OverflowPolicy::Wrap),
+            Some(highest + 3u8)
+        );
+    }
+}
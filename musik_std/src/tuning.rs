@@ -0,0 +1,274 @@
+//! Tuning systems for mapping notes to frequencies.
+//!
+//! This module provides the `Tuning` trait along with equal temperament,
+//! just intonation, and Pythagorean implementations.
+
+use crate::audio::A4_FREQUENCY;
+use crate::note::Note;
+use crate::pitch_class::PitchClass;
+use crate::semitone::SEMITONES_IN_OCTAVE;
+
+/// MIDI note number of C4, used to place the tuning root in octave 4.
+const C4_MIDI: i32 = 60;
+
+/// 5-limit just intonation ratios for each semitone above the root.
+const JUST_RATIOS: [f64; SEMITONES_IN_OCTAVE as usize] = [
+    1.0,         // 1
+    16.0 / 15.0, // ♭2
+    9.0 / 8.0,   // 2
+    6.0 / 5.0,   // ♭3
+    5.0 / 4.0,   // 3
+    4.0 / 3.0,   // 4
+    45.0 / 32.0, // ♯4
+    3.0 / 2.0,   // 5
+    8.0 / 5.0,   // ♭6
+    5.0 / 3.0,   // 6
+    9.0 / 5.0,   // ♭7
+    15.0 / 8.0,  // 7
+];
+
+/// Pythagorean ratios (stacked pure fifths) for each semitone above the root.
+const PYTHAGOREAN_RATIOS: [f64; SEMITONES_IN_OCTAVE as usize] = [
+    1.0,           // 1
+    256.0 / 243.0, // ♭2
+    9.0 / 8.0,     // 2
+    32.0 / 27.0,   // ♭3
+    81.0 / 64.0,   // 3
+    4.0 / 3.0,     // 4
+    729.0 / 512.0, // ♯4
+    3.0 / 2.0,     // 5
+    128.0 / 81.0,  // ♭6
+    27.0 / 16.0,   // 6
+    16.0 / 9.0,    // ♭7
+    243.0 / 128.0, // 7
+];
+
+/// A tuning system that maps notes to frequencies in Hz.
+///
+/// # Examples
+///
+/// ```
+/// use musik_std::{EqualTemperament, Note, Tuning};
+///
+/// let tuning = EqualTemperament::new(440.0);
+/// assert_eq!(tuning.frequency(Note::new(69)), 440.0); // A4
+/// assert_eq!(tuning.frequency(Note::new(81)), 880.0); // A5
+/// ```
+pub trait Tuning {
+    /// Returns the frequency of the given note in Hz.
+    fn frequency(&self, note: Note) -> f64;
+}
+
+/// Twelve-tone equal temperament anchored at a reference A4 frequency.
+///
+/// # Examples
+///
+/// ```
+/// use musik_std::{EqualTemperament, Note, Tuning};
+///
+/// let baroque = EqualTemperament::new(415.0);
+/// assert_eq!(baroque.frequency(Note::new(69)), 415.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EqualTemperament {
+    /// Frequency of A4 in Hz.
+    pub a4: f64,
+}
+
+impl EqualTemperament {
+    /// Creates an equal temperament tuning with the given A4 frequency.
+    pub const fn new(a4: f64) -> Self {
+        Self { a4 }
+    }
+}
+
+impl Default for EqualTemperament {
+    /// Returns standard concert pitch (A4 = 440 Hz).
+    fn default() -> Self {
+        Self::new(A4_FREQUENCY)
+    }
+}
+
+impl Tuning for EqualTemperament {
+    fn frequency(&self, note: Note) -> f64 {
+        note.frequency_with_reference(self.a4)
+    }
+}
+
+/// 5-limit just intonation built on a tuning root.
+///
+/// The root in octave 4 is tuned from A4 using equal temperament; every other
+/// note uses a pure ratio relative to the root.
+///
+/// # Examples
+///
+/// ```
+/// use musik_std::{JustIntonation, Note, Tuning, C};
+///
+/// let tuning = JustIntonation::new(C, 440.0);
+/// let c4 = tuning.frequency(Note::new(60));
+/// let e4 = tuning.frequency(Note::new(64));
+/// assert!((e4 / c4 - 5.0 / 4.0).abs() < 1e-12); // Pure major third
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct JustIntonation {
+    /// The pitch class the ratios are computed from.
+    pub root: PitchClass,
+    /// Frequency of A4 in Hz.
+    pub a4: f64,
+}
+
+impl JustIntonation {
+    /// Creates a just intonation tuning on the given root.
+    pub const fn new(root: PitchClass, a4: f64) -> Self {
+        Self { root, a4 }
+    }
+}
+
+impl Tuning for JustIntonation {
+    fn frequency(&self, note: Note) -> f64 {
+        ratio_frequency(self.root, self.a4, note, &JUST_RATIOS)
+    }
+}
+
+/// Pythagorean tuning built on a tuning root.
+///
+/// The root in octave 4 is tuned from A4 using equal temperament; every other
+/// note is derived from stacked pure fifths (3:2) relative to the root.
+///
+/// # Examples
+///
+/// ```
+/// use musik_std::{Note, Pythagorean, Tuning, C};
+///
+/// let tuning = Pythagorean::new(C, 440.0);
+/// let c4 = tuning.frequency(Note::new(60));
+/// let e4 = tuning.frequency(Note::new(64));
+/// assert!((e4 / c4 - 81.0 / 64.0).abs() < 1e-12); // Pythagorean ditone
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pythagorean {
+    /// The pitch class the ratios are computed from.
+    pub root: PitchClass,
+    /// Frequency of A4 in Hz.
+    pub a4: f64,
+}
+
+impl Pythagorean {
+    /// Creates a Pythagorean tuning on the given root.
+    pub const fn new(root: PitchClass, a4: f64) -> Self {
+        Self { root, a4 }
+    }
+}
+
+impl Tuning for Pythagorean {
+    fn frequency(&self, note: Note) -> f64 {
+        ratio_frequency(self.root, self.a4, note, &PYTHAGOREAN_RATIOS)
+    }
+}
+
+/// Computes a note's frequency from a ratio table relative to `root` in octave 4.
+fn ratio_frequency(
+    root: PitchClass,
+    a4: f64,
+    note: Note,
+    ratios: &[f64; SEMITONES_IN_OCTAVE as usize],
+) -> f64 {
+    let semitones_in_octave = SEMITONES_IN_OCTAVE as i32;
+    let root_midi = C4_MIDI + root.value() as i32;
+    let root_frequency = Note::new(root_midi as u8).frequency_with_reference(a4);
+
+    let offset = note.semitone() as i32 - root_midi;
+    let octaves = offset.div_euclid(semitones_in_octave);
+    let step = offset.rem_euclid(semitones_in_octave) as usize;
+
+    root_frequency * ratios[step] * 2f64.powi(octaves)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pitch_class::{A, C, D};
+
+    const EPSILON: f64 = 1e-9;
+
+    #[test]
+    fn test_equal_temperament_reference() {
+        let tuning = EqualTemperament::default();
+
+        assert_eq!(tuning.a4, 440.0);
+        for midi in 0..=127u8 {
+            let note = Note::new(midi);
+            assert_eq!(tuning.frequency(note), note.frequency());
+        }
+        assert_eq!(tuning.frequency(Note::new(69)), 440.0); // A4
+        assert_eq!(tuning.frequency(Note::new(57)), 220.0); // A3
+        assert_eq!(tuning.frequency(Note::new(81)), 880.0); // A5
+
+        let c4 = tuning.frequency(Note::new(60));
+        assert!((c4 - 261.625_565_300_6).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_equal_temperament_semitone_ratio() {
+        let tuning = EqualTemperament::new(440.0);
+        let semitone_ratio = 2f64.powf(1.0 / 12.0);
+
+        for midi in 0..127u8 {
+            let low = tuning.frequency(Note::new(midi));
+            let high = tuning.frequency(Note::new(midi + 1));
+            assert!((high / low - semitone_ratio).abs() < EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_just_intonation_ratios() {
+        let tuning = JustIntonation::new(C, 440.0);
+        let c4 = tuning.frequency(Note::new(60));
+
+        // Root is tuned in equal temperament
+        assert!((c4 - EqualTemperament::new(440.0).frequency(Note::new(60))).abs() < EPSILON);
+
+        // Pure intervals above the root
+        assert!((tuning.frequency(Note::new(67)) / c4 - 3.0 / 2.0).abs() < 1e-12); // Fifth
+        assert!((tuning.frequency(Note::new(64)) / c4 - 5.0 / 4.0).abs() < 1e-12); // Major third
+        assert!((tuning.frequency(Note::new(65)) / c4 - 4.0 / 3.0).abs() < 1e-12); // Fourth
+
+        // Octaves double
+        assert!((tuning.frequency(Note::new(72)) / c4 - 2.0).abs() < 1e-12);
+        assert!((tuning.frequency(Note::new(79)) / c4 - 3.0).abs() < 1e-12); // G5
+        assert!((c4 / tuning.frequency(Note::new(48)) - 2.0).abs() < 1e-12); // C3
+    }
+
+    #[test]
+    fn test_just_intonation_root_is_a() {
+        let tuning = JustIntonation::new(A, 440.0);
+
+        assert_eq!(tuning.frequency(Note::new(69)), 440.0);
+        assert!((tuning.frequency(Note::new(76)) - 660.0).abs() < EPSILON); // E5 = 3:2
+        assert!((tuning.frequency(Note::new(73)) - 550.0).abs() < EPSILON); // C#5 = 5:4
+    }
+
+    #[test]
+    fn test_pythagorean_ratios() {
+        let tuning = Pythagorean::new(D, 440.0);
+        let d4 = tuning.frequency(Note::new(62));
+
+        assert!((tuning.frequency(Note::new(69)) / d4 - 3.0 / 2.0).abs() < 1e-12); // Fifth
+        assert!((tuning.frequency(Note::new(64)) / d4 - 9.0 / 8.0).abs() < 1e-12); // Whole tone
+        assert!((tuning.frequency(Note::new(66)) / d4 - 81.0 / 64.0).abs() < 1e-12); // Ditone
+        assert!((tuning.frequency(Note::new(74)) / d4 - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_tunings_differ_from_equal_temperament() {
+        let equal = EqualTemperament::new(440.0);
+        let just = JustIntonation::new(C, 440.0);
+        let pythagorean = Pythagorean::new(C, 440.0);
+        let e4 = Note::new(64);
+
+        // Just major third is flatter, Pythagorean sharper than equal temperament
+        assert!(just.frequency(e4) < equal.frequency(e4));
+        assert!(pythagorean.frequency(e4) > equal.frequency(e4));
+    }
+}